@@ -0,0 +1,82 @@
+//! Boost computation for incentives with a [Locker].
+
+use crate::ErrorCode;
+use crate::*;
+use locked_voter::{Escrow, Locker};
+
+/// Returns the voting power of `owner` in the boost locker.
+///
+/// The escrow must be the owner's escrow PDA in the locker. If it is not initialized, the owner
+/// has no voting power.
+///
+/// # Arguments
+///
+/// * `locker` - The boost locker of the incentive
+/// * `escrow` - The vote escrow of the deposit owner
+/// * `owner` - The owner of the deposit
+/// * `current_time` - The current block timestamp
+///
+pub fn voting_power(
+    locker: &Account<Locker>,
+    escrow: &AccountInfo,
+    owner: &Pubkey,
+    current_time: i64,
+) -> Result<u64> {
+    let (escrow_address, _) = Pubkey::find_program_address(
+        &[b"Escrow".as_ref(), locker.key().as_ref(), owner.as_ref()],
+        &locked_voter::ID,
+    );
+    require!(
+        escrow.key() == escrow_address,
+        ErrorCode::InvalidBoostEscrow
+    );
+
+    if escrow.data_is_empty() {
+        return Ok(0);
+    }
+    let escrow = Account::<Escrow>::try_from(escrow)?;
+
+    Ok(locker
+        .params
+        .calculate_voter_power(&escrow, current_time)
+        .unwrap())
+}
+
+/// Snapshots the voting power of a stake and recomputes its effective liquidity.
+///
+/// # Arguments
+///
+/// * `incentive` - The boosted incentive of the stake
+/// * `stake` - The stake, with `liquidity` set to the current liquidity of the position
+/// * `locker` - The boost locker of the incentive
+/// * `voting_power` - The current voting power of the deposit owner
+/// * `total_pool_liquidity` - The total pool liquidity
+///
+pub fn update_boost(
+    incentive: &mut Incentive,
+    stake: &mut Stake,
+    locker: &Locker,
+    voting_power: u64,
+    total_pool_liquidity: u64,
+) {
+    incentive.total_staked_voting_power =
+        incentive.total_staked_voting_power - stake.voting_power + voting_power;
+    stake.voting_power = voting_power;
+
+    let total_voting_power = match incentive.boost_denominator {
+        // This is a hypothetical ceiling, not the total locked power at a point of time.
+        BoostDenominator::MaxVotingPower => locker
+            .locked_supply
+            .checked_mul(locker.params.max_stake_vote_multiplier.into())
+            .unwrap(),
+        BoostDenominator::LockedSupply => locker.locked_supply,
+    };
+
+    stake.effective_liquidity = reward_math::compute_effective_liquidity(
+        stake.liquidity,
+        total_pool_liquidity,
+        voting_power,
+        total_voting_power,
+        incentive.boost_base_percent,
+    );
+}
@@ -0,0 +1,44 @@
+use crate::ErrorCode;
+use crate::*;
+
+/// Accounts for [cykura_staker::accept_admin].
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// [Config]
+    #[account(
+        mut,
+        constraint = config.pending_admin == Some(pending_admin.key()) @ ErrorCode::OnlyPendingAdminCanAccept
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The address nominated to become the admin.
+    pub pending_admin: Signer<'info>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    /// Makes the pending admin the admin of the program.
+    pub fn accept_admin(&mut self) -> Result<()> {
+        let config = &mut self.config;
+        let previous_admin = config.admin;
+
+        config.admin = self.pending_admin.key();
+        config.pending_admin = None;
+
+        emit!(AdminTransferredEvent {
+            previous_admin,
+            admin: config.admin,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when the admin of the program has changed.
+pub struct AdminTransferredEvent {
+    /// The previous admin.
+    pub previous_admin: Pubkey,
+
+    /// The new admin.
+    pub admin: Pubkey,
+}
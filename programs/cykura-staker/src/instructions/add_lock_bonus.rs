@@ -0,0 +1,90 @@
+use crate::ErrorCode;
+use crate::*;
+use anchor_spl::token;
+use locked_voter::Locker;
+
+/// Accounts for [cykura_staker::add_lock_bonus].
+#[derive(Accounts)]
+pub struct AddLockBonus<'info> {
+    /// [Incentive]
+    #[account(
+        mut,
+        constraint = incentive.boost_locker == Some(locker.key()) @ErrorCode::InvalidLocker,
+    )]
+    pub incentive: Account<'info, Incentive>,
+
+    /// The program [Config].
+    #[account(seeds = [b"Config".as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// The boost locker of the incentive.
+    pub locker: Account<'info, Locker>,
+
+    /// The reward vault of the locker token.
+    #[account(
+        mut,
+        seeds = [
+            b"Vault".as_ref(),
+            incentive.key().as_ref(),
+            locker.token_mint.as_ref()
+        ],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// The account paying the lock bonus.
+    pub payer: Signer<'info>,
+
+    /// The token account of the payer.
+    /// CHECK: mint and signer are validated in the CPI.
+    #[account(mut)]
+    pub payer_token_account: UncheckedAccount<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> AddLockBonus<'info> {
+    /// Adds tokens to the reserve paying the lock bonus of a boosted [Incentive].
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The amount of locker tokens added to the reserve.
+    ///
+    pub fn add_lock_bonus(&mut self, amount: u64) -> Result<()> {
+        let incentive = &mut self.incentive;
+        let index = incentive
+            .reward_index(&self.vault.mint)
+            .ok_or(ErrorCode::InvalidRewardToken)?;
+        incentive.rewards[index].lock_bonus_reserve += amount;
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.payer_token_account.to_account_info(),
+                    to: self.vault.to_account_info(),
+                    authority: self.payer.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        emit!(AddLockBonusEvent {
+            incentive: incentive.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when tokens are added to the lock bonus reserve of an [Incentive].
+pub struct AddLockBonusEvent {
+    /// [Incentive] address.
+    pub incentive: Pubkey,
+
+    /// The amount added to the reserve.
+    pub amount: u64,
+}
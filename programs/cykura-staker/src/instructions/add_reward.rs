@@ -1,5 +1,5 @@
+use crate::ErrorCode;
 use crate::*;
-use anchor_spl::associated_token::get_associated_token_address;
 use anchor_spl::token;
 
 /// Accounts for [cykura_staker::add_reward].
@@ -9,13 +9,19 @@ pub struct AddReward<'info> {
     #[account(mut)]
     pub incentive: Account<'info, Incentive>,
 
-    /// The vault to hold reward tokens.
+    /// The program [Config].
+    #[account(seeds = [b"Config".as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// The vault to hold tokens of the reward being added.
     #[account(
         mut,
-        address = get_associated_token_address(
-            &Pubkey::find_program_address(&[], &crate::id()).0,
-            &incentive.reward_token
-        )
+        seeds = [
+            b"Vault".as_ref(),
+            incentive.key().as_ref(),
+            vault.mint.as_ref()
+        ],
+        bump
     )]
     pub vault: Account<'info, TokenAccount>,
 
@@ -40,7 +46,10 @@ impl<'info> AddReward<'info> {
     ///
     pub fn add_reward(&mut self, reward: u64) -> Result<()> {
         let incentive = &mut self.incentive;
-        incentive.total_reward_unclaimed += reward;
+        let index = incentive
+            .reward_index(&self.vault.mint)
+            .ok_or(ErrorCode::InvalidRewardToken)?;
+        incentive.rewards[index].total_reward_unclaimed += reward;
 
         token::transfer(
             CpiContext::new(
@@ -56,6 +65,7 @@ impl<'info> AddReward<'info> {
 
         emit!(AddRewardEvent {
             incentive: incentive.key(),
+            reward_token: self.vault.mint,
             reward,
         });
 
@@ -69,6 +79,9 @@ pub struct AddRewardEvent {
     /// [Incentive] address.
     pub incentive: Pubkey,
 
+    /// The token being added as a reward.
+    pub reward_token: Pubkey,
+
     /// The reward amount added.
     pub reward: u64,
 }
@@ -0,0 +1,85 @@
+use crate::*;
+
+/// Accounts for [cykura_staker::add_reward_token].
+#[derive(Accounts)]
+pub struct AddRewardToken<'info> {
+    /// [Incentive]
+    #[account(mut, has_one = refundee)]
+    pub incentive: Account<'info, Incentive>,
+
+    /// The token being added as a reward.
+    pub reward_token: Account<'info, Mint>,
+
+    /// The allowlist marker of the reward token.
+    /// CHECK: The address is verified using seeds. The marker need not exist for permissionless incentives.
+    #[account(seeds = [b"AllowedMint".as_ref(), reward_token.key().as_ref()], bump)]
+    pub allowed_reward_token: UncheckedAccount<'info>,
+
+    /// The vault holding reward tokens of the incentive.
+    #[account(
+        init,
+        seeds = [
+            b"Vault".as_ref(),
+            incentive.key().as_ref(),
+            reward_token.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        token::mint = reward_token,
+        token::authority = stake_manager
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// The root program account which acts as the vault authority.
+    /// CHECK: The address is verified using seeds and bump.
+    #[account(seeds = [], bump)]
+    pub stake_manager: UncheckedAccount<'info>,
+
+    /// The address which receives any remaining reward tokens when the incentive is ended.
+    pub refundee: Signer<'info>,
+
+    /// Payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+
+    /// Rent sysvar.
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> AddRewardToken<'info> {
+    /// Adds another reward token to an [Incentive].
+    pub fn add_reward_token(&mut self) -> Result<()> {
+        let incentive = &mut self.incentive;
+
+        let index = incentive.reward_token_count();
+        incentive.rewards[index] = IncentiveReward {
+            mint: self.reward_token.key(),
+            total_reward_unclaimed: 0,
+            lock_bonus_reserve: 0,
+        };
+
+        emit!(RewardTokenAddedEvent {
+            incentive: incentive.key(),
+            reward_token: self.reward_token.key(),
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when a reward token is added to an [Incentive].
+pub struct RewardTokenAddedEvent {
+    /// [Incentive] address.
+    #[index]
+    pub incentive: Pubkey,
+
+    /// The token being added as a reward.
+    pub reward_token: Pubkey,
+}
@@ -0,0 +1,56 @@
+use crate::*;
+use std::mem::size_of;
+
+/// Accounts for [cykura_staker::allow_mint].
+#[derive(Accounts)]
+pub struct AllowMint<'info> {
+    /// [AllowedMint]
+    #[account(
+        init,
+        seeds = [b"AllowedMint".as_ref(), mint.key().as_ref()],
+        bump,
+        payer = admin,
+        space = 8 + size_of::<AllowedMint>()
+    )]
+    pub allowed_mint: Account<'info, AllowedMint>,
+
+    /// The token to allow as a reward.
+    pub mint: Account<'info, Mint>,
+
+    /// [Config]
+    #[account(has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    /// The admin of the program.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AllowMint<'info> {
+    /// Adds a reward token to the allowlist.
+    pub fn allow_mint(&mut self, bump: u8) -> Result<()> {
+        self.allowed_mint.bump = bump;
+        self.allowed_mint.mint = self.mint.key();
+
+        emit!(MintAllowedEvent {
+            mint: self.mint.key(),
+            allowed: true,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when a reward token has been added to or removed from the allowlist.
+pub struct MintAllowedEvent {
+    /// The token mint.
+    #[index]
+    pub mint: Pubkey,
+
+    /// Whether the token is allowed.
+    pub allowed: bool,
+}
@@ -0,0 +1,56 @@
+use crate::*;
+use std::mem::size_of;
+
+/// Accounts for [cykura_staker::allow_pool].
+#[derive(Accounts)]
+pub struct AllowPool<'info> {
+    /// [AllowedPool]
+    #[account(
+        init,
+        seeds = [b"AllowedPool".as_ref(), pool.key().as_ref()],
+        bump,
+        payer = admin,
+        space = 8 + size_of::<AllowedPool>()
+    )]
+    pub allowed_pool: Account<'info, AllowedPool>,
+
+    /// The Cykura pool to allow.
+    pub pool: AccountLoader<'info, PoolState>,
+
+    /// [Config]
+    #[account(has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    /// The admin of the program.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AllowPool<'info> {
+    /// Adds a pool to the allowlist.
+    pub fn allow_pool(&mut self, bump: u8) -> Result<()> {
+        self.allowed_pool.bump = bump;
+        self.allowed_pool.pool = self.pool.key();
+
+        emit!(PoolAllowedEvent {
+            pool: self.pool.key(),
+            allowed: true,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when a pool has been added to or removed from the allowlist.
+pub struct PoolAllowedEvent {
+    /// The Cykura pool.
+    #[index]
+    pub pool: Pubkey,
+
+    /// Whether the pool is allowed.
+    pub allowed: bool,
+}
@@ -0,0 +1,41 @@
+use crate::ErrorCode;
+use crate::*;
+
+/// Accounts for [cykura_staker::cancel_incentive].
+#[derive(Accounts)]
+pub struct CancelIncentive<'info> {
+    /// The accounts refunding and closing the [Incentive].
+    pub end_incentive: EndIncentive<'info>,
+
+    /// The authority of the incentive.
+    #[account(
+        address = end_incentive.incentive.authority @ ErrorCode::OnlyAuthorityCanManageIncentive
+    )]
+    pub authority: Signer<'info>,
+}
+
+impl<'info> CancelIncentive<'info> {
+    /// Cancels an [Incentive], refunding all unclaimed rewards to the refundee
+    ///
+    /// The vault and refundee token account of every reward token other than the first are
+    /// passed in pairs as remaining accounts.
+    pub fn cancel_incentive(
+        &mut self,
+        bump: u8,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        emit!(CancelIncentiveEvent {
+            incentive: self.end_incentive.incentive.key(),
+        });
+
+        self.end_incentive.end_incentive(bump, remaining_accounts)
+    }
+}
+
+#[event]
+/// Event emitted when an [Incentive] has been cancelled.
+pub struct CancelIncentiveEvent {
+    /// The incentive which is cancelled.
+    #[index]
+    pub incentive: Pubkey,
+}
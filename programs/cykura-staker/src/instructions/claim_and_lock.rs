@@ -0,0 +1,185 @@
+use crate::ErrorCode;
+use crate::*;
+use locked_voter::cpi::accounts::{ExtendLockDuration, IncreaseLockedAmount};
+use locked_voter::program::LockedVoter;
+use locked_voter::{Escrow, Locker};
+use rewards::RewardPayout;
+
+/// Accounts for [cykura_staker::claim_and_lock].
+#[derive(Accounts)]
+pub struct ClaimAndLock<'info> {
+    /// [Reward].
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub reward: Account<'info, Reward>,
+
+    /// The reward owner.
+    pub owner: Signer<'info>,
+
+    /// The program [Config].
+    #[account(seeds = [b"Config".as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// The boosted incentive of the reward.
+    #[account(
+        mut,
+        address = reward.incentive,
+        constraint = incentive.boost_locker == Some(locker.key()) @ErrorCode::InvalidLocker,
+    )]
+    pub incentive: Account<'info, Incentive>,
+
+    ///  The reward vault of the incentive.
+    #[account(
+        mut,
+        seeds = [
+            b"Vault".as_ref(),
+            reward.incentive.as_ref(),
+            reward.reward_token.as_ref()
+        ],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// The root program account which acts as the vault authority.
+    /// CHECK: The address is verified using seeds and bump.
+    #[account(seeds = [], bump)]
+    pub stake_manager: UncheckedAccount<'info>,
+
+    /// The token account of the treasury receiving the protocol fee.
+    /// CHECK: The owner and mint are verified when a fee is charged.
+    #[account(mut)]
+    pub treasury_token_account: UncheckedAccount<'info>,
+
+    /// The boost locker of the incentive.
+    #[account(
+        mut,
+        constraint = locker.token_mint == reward.reward_token @ErrorCode::RewardTokenIsNotLockerToken,
+    )]
+    pub locker: Account<'info, Locker>,
+
+    /// The vote escrow of the reward owner.
+    #[account(
+        mut,
+        has_one = locker,
+        has_one = owner @ErrorCode::InvalidBoostEscrow,
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    /// The token account holding the locked tokens of the escrow.
+    /// CHECK: Verified by the locked voter program.
+    #[account(mut)]
+    pub escrow_tokens: UncheckedAccount<'info>,
+
+    /// The locked voter program.
+    pub locked_voter_program: Program<'info, LockedVoter>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimAndLock<'info> {
+    /// Locks `amount_requested` of accrued `reward_token` rewards, less the protocol fee, in the
+    /// escrow of the reward owner.
+    ///
+    /// The lock bonus of the incentive is added if the escrow stays locked for at least
+    /// `MIN_LOCK_BONUS_DURATION` from now, up to the lock bonus reserve of the incentive.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_time` - The current block timestamp.
+    /// * `amount_requested` - The amount to claim, or zero to claim everything owed.
+    /// * `lock_duration` - The new duration of the lock from now, if it should be extended.
+    ///
+    pub fn claim_and_lock(
+        &mut self,
+        bump: u8,
+        current_time: i64,
+        amount_requested: u64,
+        lock_duration: Option<i64>,
+    ) -> Result<()> {
+        let (amount, fee) = RewardPayout {
+            config: &self.config,
+            stake_manager: self.stake_manager.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            bump,
+        }
+        .withdraw(
+            &mut self.reward,
+            &self.vault.to_account_info(),
+            &self.treasury_token_account,
+            amount_requested,
+        )?;
+
+        if let Some(lock_duration) = lock_duration {
+            locked_voter::cpi::extend_lock_duration(
+                CpiContext::new(
+                    self.locked_voter_program.to_account_info(),
+                    ExtendLockDuration {
+                        locker: self.locker.to_account_info(),
+                        escrow: self.escrow.to_account_info(),
+                        escrow_owner: self.owner.to_account_info(),
+                    },
+                ),
+                lock_duration,
+            )?;
+            self.escrow.reload()?;
+        }
+
+        let incentive = &mut self.incentive;
+        let mut bonus = 0;
+        if self.escrow.escrow_ends_at >= current_time + MIN_LOCK_BONUS_DURATION {
+            let index = incentive
+                .reward_index(&self.reward.reward_token)
+                .ok_or(ErrorCode::InvalidRewardToken)?;
+            bonus = (amount as u128 * incentive.lock_bonus_bps as u128 / 10000) as u64;
+            bonus = bonus.min(incentive.rewards[index].lock_bonus_reserve);
+            incentive.rewards[index].lock_bonus_reserve -= bonus;
+        }
+
+        let seeds: [&[u8]; 1] = [&[bump]];
+        locked_voter::cpi::increase_locked_amount(
+            CpiContext::new_with_signer(
+                self.locked_voter_program.to_account_info(),
+                IncreaseLockedAmount {
+                    locker: self.locker.to_account_info(),
+                    escrow: self.escrow.to_account_info(),
+                    escrow_tokens: self.escrow_tokens.to_account_info(),
+                    payer: self.stake_manager.to_account_info(),
+                    source_tokens: self.vault.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount - fee + bonus,
+        )?;
+
+        emit!(RewardClaimed {
+            to: self.escrow_tokens.key(),
+            reward: amount,
+            fee,
+        });
+        emit!(RewardLockedEvent {
+            escrow: self.escrow.key(),
+            amount: amount - fee + bonus,
+            bonus,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when a claimed reward has been locked in an escrow.
+pub struct RewardLockedEvent {
+    /// The escrow where the reward was locked.
+    #[index]
+    pub escrow: Pubkey,
+
+    /// The amount of reward tokens locked, including the bonus.
+    pub amount: u64,
+
+    /// The lock bonus paid from the reserve of the incentive.
+    pub bonus: u64,
+}
@@ -1,6 +1,5 @@
 use crate::*;
-use anchor_spl::associated_token::get_associated_token_address;
-use anchor_spl::token;
+use rewards::RewardPayout;
 
 /// Accounts for [cykura_staker::claim_reward].
 #[derive(Accounts)]
@@ -15,11 +14,19 @@ pub struct ClaimReward<'info> {
     /// The reward owner.
     pub owner: Signer<'info>,
 
-    ///  The reward vault.
+    /// The program [Config].
+    #[account(seeds = [b"Config".as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    ///  The reward vault of the incentive.
     #[account(
         mut,
-        // associated_token is bugged in anchor v0.22
-        address = get_associated_token_address(stake_manager.key, &reward.reward_token)
+        seeds = [
+            b"Vault".as_ref(),
+            reward.incentive.as_ref(),
+            reward.reward_token.as_ref()
+        ],
+        bump
     )]
     pub vault: Account<'info, TokenAccount>,
 
@@ -34,40 +41,32 @@ pub struct ClaimReward<'info> {
     #[account(mut)]
     pub to: UncheckedAccount<'info>,
 
+    /// The token account of the treasury receiving the protocol fee.
+    /// CHECK: The owner and mint are verified when a fee is charged.
+    #[account(mut)]
+    pub treasury_token_account: UncheckedAccount<'info>,
+
     /// Token program.
     pub token_program: Program<'info, Token>,
 }
 
 impl<'info> ClaimReward<'info> {
-    /// Transfers `amount_requested` of accrued `reward_token` rewards from the contract to the recipient `to`
+    /// Transfers `amount_requested` of accrued `reward_token` rewards from the contract to the recipient `to`,
+    /// less the protocol fee which is sent to the treasury
     pub fn claim_reward(&mut self, amount_requested: u64, bump: u8) -> Result<()> {
-        let mut reward = self.reward.rewards_owed;
-        if amount_requested > 0 && amount_requested < reward {
-            reward = amount_requested;
+        RewardPayout {
+            config: &self.config,
+            stake_manager: self.stake_manager.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            bump,
         }
-
-        self.reward.rewards_owed -= reward;
-
-        let seeds: [&[u8]; 1] = [&[bump]];
-        token::transfer(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                token::Transfer {
-                    from: self.vault.to_account_info(),
-                    to: self.to.to_account_info(),
-                    authority: self.stake_manager.to_account_info(),
-                },
-                &[&seeds[..]],
-            ),
-            reward,
-        )?;
-
-        emit!(RewardClaimed {
-            to: self.to.key(),
-            reward,
-        });
-
-        Ok(())
+        .pay(
+            &mut self.reward,
+            &self.vault.to_account_info(),
+            &self.to,
+            &self.treasury_token_account,
+            amount_requested,
+        )
     }
 }
 
@@ -77,6 +76,9 @@ pub struct RewardClaimed {
     /// The address where claimed rewards were sent to
     pub to: Pubkey,
 
-    /// The amount of reward tokens claimed
+    /// The amount of reward tokens claimed, including the fee
     pub reward: u64,
+
+    /// The amount of reward tokens paid to the treasury as protocol fee
+    pub fee: u64,
 }
@@ -0,0 +1,45 @@
+use crate::ErrorCode;
+use crate::*;
+use rewards::RewardPayout;
+
+/// Accounts for [cykura_staker::claim_rewards_multi].
+#[derive(Accounts)]
+pub struct ClaimRewardsMulti<'info> {
+    /// The reward owner.
+    pub owner: Signer<'info>,
+
+    /// The program [Config].
+    #[account(seeds = [b"Config".as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// The root program account which acts as the vault authority.
+    /// CHECK: The address is verified using seeds and bump.
+    #[account(seeds = [], bump)]
+    pub stake_manager: UncheckedAccount<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimRewardsMulti<'info> {
+    /// Transfers all accrued rewards of every [Reward] account passed in the remaining accounts,
+    /// less the protocol fee which is sent to the treasury, see [RewardPayout::pay_all].
+    pub fn claim_rewards_multi(
+        &mut self,
+        bump: u8,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            !remaining_accounts.is_empty() && remaining_accounts.len() % 4 == 0,
+            ErrorCode::MissingRewardAccount
+        );
+
+        RewardPayout {
+            config: &self.config,
+            stake_manager: self.stake_manager.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            bump,
+        }
+        .pay_all(&self.owner.key(), remaining_accounts)
+    }
+}
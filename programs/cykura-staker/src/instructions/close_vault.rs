@@ -0,0 +1,137 @@
+use crate::ErrorCode;
+use crate::*;
+use anchor_spl::token;
+
+/// Accounts for [cykura_staker::close_vault].
+#[derive(Accounts)]
+#[instruction(start_time: i64, end_time: i64)]
+pub struct CloseVault<'info> {
+    /// The ended incentive of the vault.
+    /// CHECK: The account must have been closed by end_incentive. The address is verified using
+    /// the seeds of the incentive, which prove the refundee.
+    #[account(
+        seeds = [
+            b"Incentive".as_ref(),
+            reward_token.key().as_ref(),
+            pool.key().as_ref(),
+            refundee.key().as_ref(),
+            &start_time.to_be_bytes(),
+            &end_time.to_be_bytes()
+        ],
+        bump,
+        constraint = incentive.data_is_empty() @ ErrorCode::IncentiveNotEnded
+    )]
+    pub incentive: UncheckedAccount<'info>,
+
+    /// The first reward token of the incentive, used to derive its address.
+    /// CHECK: Verified by the incentive seeds.
+    pub reward_token: UncheckedAccount<'info>,
+
+    /// The pool of the incentive.
+    /// CHECK: Verified by the incentive seeds.
+    pub pool: UncheckedAccount<'info>,
+
+    /// The refundee of the incentive.
+    /// CHECK: Verified by the incentive seeds.
+    pub refundee: UncheckedAccount<'info>,
+
+    /// The token account of the refundee receiving the tokens left in the vault.
+    #[account(
+        mut,
+        constraint = refundee_token_account.owner == refundee.key()
+            && refundee_token_account.mint == vault.mint
+            @ ErrorCode::InvalidRefundeeTokenAccount
+    )]
+    pub refundee_token_account: Account<'info, TokenAccount>,
+
+    /// The reward vault to close.
+    #[account(
+        mut,
+        seeds = [
+            b"Vault".as_ref(),
+            incentive.key().as_ref(),
+            vault.mint.as_ref()
+        ],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// The root program account which acts as the vault authority.
+    /// CHECK: The address is verified using seeds and bump.
+    #[account(seeds = [], bump)]
+    pub stake_manager: UncheckedAccount<'info>,
+
+    /// The program [Config].
+    #[account(seeds = [b"Config".as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// The protocol treasury, receiving the rent of the vault.
+    /// CHECK: The address is verified against the config.
+    #[account(mut, address = config.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CloseVault<'info> {
+    /// Closes a vault left open by [cykura_staker::end_incentive]. Tokens left in the vault are
+    /// swept to the refundee first. The payer of the incentive is no longer known, so the rent
+    /// goes to the treasury.
+    pub fn close_vault(&self, bump: u8) -> Result<()> {
+        let seeds: [&[u8]; 1] = [&[bump]];
+        let sweep = self.vault.amount;
+        let rent = self.vault.to_account_info().lamports();
+
+        if sweep > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: self.vault.to_account_info(),
+                        to: self.refundee_token_account.to_account_info(),
+                        authority: self.stake_manager.to_account_info(),
+                    },
+                    &[&seeds[..]],
+                ),
+                sweep,
+            )?;
+        }
+
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            token::CloseAccount {
+                account: self.vault.to_account_info(),
+                destination: self.treasury.to_account_info(),
+                authority: self.stake_manager.to_account_info(),
+            },
+            &[&seeds[..]],
+        ))?;
+
+        emit!(VaultClosedEvent {
+            incentive: self.incentive.key(),
+            vault: self.vault.key(),
+            sweep,
+            rent,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when the vault of an ended incentive is closed.
+pub struct VaultClosedEvent {
+    /// The ended incentive.
+    #[index]
+    pub incentive: Pubkey,
+
+    /// The closed vault.
+    pub vault: Pubkey,
+
+    /// The tokens left in the vault which were swept to the refundee.
+    pub sweep: u64,
+
+    /// The lamports sent to the treasury.
+    pub rent: u64,
+}
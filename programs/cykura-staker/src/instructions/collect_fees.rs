@@ -0,0 +1,169 @@
+use crate::ErrorCode;
+use crate::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use cyclos_core::cpi::accounts::CollectFromTokenized;
+
+/// Accounts for [cykura_staker::collect_fees].
+#[derive(Accounts)]
+pub struct CollectFees<'info> {
+    /// [Deposit] whose position fees are collected.
+    #[account(has_one = owner @ErrorCode::OnlyOwnerCanCollectFees)]
+    pub deposit: Account<'info, Deposit>,
+
+    /// The owner of the deposit.
+    pub owner: Signer<'info>,
+
+    /// The vault which holds the deposited token.
+    #[account(address = get_associated_token_address(stake_manager.key, &deposit.mint))]
+    pub deposit_vault: Account<'info, TokenAccount>,
+
+    /// The root program account which owns the deposited token.
+    /// CHECK: The address is verified using seeds and bump.
+    #[account(seeds = [], bump)]
+    pub stake_manager: UncheckedAccount<'info>,
+
+    /// The token account of the owner receiving token_0.
+    #[account(mut, constraint = recipient_wallet_0.owner == deposit.owner)]
+    pub recipient_wallet_0: Account<'info, TokenAccount>,
+
+    /// The token account of the owner receiving token_1.
+    #[account(mut, constraint = recipient_wallet_1.owner == deposit.owner)]
+    pub recipient_wallet_1: Account<'info, TokenAccount>,
+
+    /// The Cykura core program.
+    /// CHECK: The address is verified against the core program ID.
+    #[account(address = cyclos_core::ID)]
+    pub core_program: UncheckedAccount<'info>,
+
+    /// The account having metadata of the Cykura Position NFT.
+    /// CHECK: Verified by the core program against the deposited token.
+    #[account(mut)]
+    pub tokenized_position: UncheckedAccount<'info>,
+
+    /// The core factory, which owns the core position.
+    /// CHECK: Verified by the core program.
+    pub factory_state: UncheckedAccount<'info>,
+
+    /// The liquidity pool to which the LP position belongs.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub pool: UncheckedAccount<'info>,
+
+    /// The core position backing the position NFT.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub core_position: UncheckedAccount<'info>,
+
+    /// The lower tick account of the position.
+    /// CHECK: Verified by the core program.
+    pub tick_lower: UncheckedAccount<'info>,
+
+    /// The upper tick account of the position.
+    /// CHECK: Verified by the core program.
+    pub tick_upper: UncheckedAccount<'info>,
+
+    /// The bitmap of the lower tick.
+    /// CHECK: Verified by the core program.
+    pub bitmap_lower: UncheckedAccount<'info>,
+
+    /// The bitmap of the upper tick.
+    /// CHECK: Verified by the core program.
+    pub bitmap_upper: UncheckedAccount<'info>,
+
+    /// The latest oracle observation for the pool.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub latest_observation: UncheckedAccount<'info>,
+
+    /// The next oracle observation for the pool.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub next_observation: UncheckedAccount<'info>,
+
+    /// The pool vault of token_0.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub vault_0: UncheckedAccount<'info>,
+
+    /// The pool vault of token_1.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub vault_1: UncheckedAccount<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CollectFees<'info> {
+    /// Collects the fees and withdrawn liquidity owed to a deposited position, on behalf of the
+    /// stake manager. Stakes of the deposit are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_0_max` - The max amount of token_0 to collect.
+    /// * `amount_1_max` - The max amount of token_1 to collect.
+    ///
+    pub fn collect_fees(&mut self, bump: u8, amount_0_max: u64, amount_1_max: u64) -> Result<()> {
+        let balance_0 = self.recipient_wallet_0.amount;
+        let balance_1 = self.recipient_wallet_1.amount;
+
+        let seeds: [&[u8]; 1] = [&[bump]];
+        cyclos_core::cpi::collect_from_tokenized(
+            CpiContext::new_with_signer(
+                self.core_program.to_account_info(),
+                CollectFromTokenized {
+                    owner_or_delegate: self.stake_manager.to_account_info(),
+                    nft_account: self.deposit_vault.to_account_info(),
+                    tokenized_position_state: self.tokenized_position.to_account_info(),
+                    factory_state: self.factory_state.to_account_info(),
+                    pool_state: self.pool.to_account_info(),
+                    core_position_state: self.core_position.to_account_info(),
+                    tick_lower_state: self.tick_lower.to_account_info(),
+                    tick_upper_state: self.tick_upper.to_account_info(),
+                    bitmap_lower_state: self.bitmap_lower.to_account_info(),
+                    bitmap_upper_state: self.bitmap_upper.to_account_info(),
+                    latest_observation_state: self.latest_observation.to_account_info(),
+                    next_observation_state: self.next_observation.to_account_info(),
+                    vault_0: self.vault_0.to_account_info(),
+                    vault_1: self.vault_1.to_account_info(),
+                    recipient_wallet_0: self.recipient_wallet_0.to_account_info(),
+                    recipient_wallet_1: self.recipient_wallet_1.to_account_info(),
+                    core_program: self.core_program.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount_0_max,
+            amount_1_max,
+        )?;
+
+        self.recipient_wallet_0.reload()?;
+        self.recipient_wallet_1.reload()?;
+
+        emit!(CollectFeesEvent {
+            mint: self.deposit.mint,
+            owner: self.deposit.owner,
+            amount_0: self.recipient_wallet_0.amount - balance_0,
+            amount_1: self.recipient_wallet_1.amount - balance_1,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when the fees of a deposited position have been collected.
+pub struct CollectFeesEvent {
+    /// The unique identifier of a Cykura LP token.
+    #[index]
+    pub mint: Pubkey,
+
+    /// The owner of the deposit.
+    pub owner: Pubkey,
+
+    /// The amount of token_0 collected.
+    pub amount_0: u64,
+
+    /// The amount of token_1 collected.
+    pub amount_1: u64,
+}
@@ -1,3 +1,4 @@
+use crate::ErrorCode;
 use crate::*;
 use locked_voter::Locker;
 use std::mem::size_of;
@@ -26,6 +27,26 @@ pub struct CreateIncentiveBoosted<'info> {
     /// The token being distributed as a reward.
     pub reward_token: Account<'info, Mint>,
 
+    /// The vault holding reward tokens of the incentive.
+    #[account(
+        init,
+        seeds = [
+            b"Vault".as_ref(),
+            incentive.key().as_ref(),
+            reward_token.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        token::mint = reward_token,
+        token::authority = stake_manager
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// The root program account which acts as the vault authority.
+    /// CHECK: The address is verified using seeds and bump.
+    #[account(seeds = [], bump)]
+    pub stake_manager: UncheckedAccount<'info>,
+
     /// The Cyclos pool to incentivize.
     pub pool: AccountLoader<'info, PoolState>,
 
@@ -33,13 +54,40 @@ pub struct CreateIncentiveBoosted<'info> {
     /// CHECK: Refundee can be an arbitrary address
     pub refundee: UncheckedAccount<'info>,
 
-    /// Payer of the initialization.
-    #[account(mut)]
+    /// The address which can extend or cancel the incentive.
+    /// CHECK: Authority can be an arbitrary address
+    pub authority: UncheckedAccount<'info>,
+
+    /// The program [Config].
+    #[account(seeds = [b"Config".as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// The allowlist marker of the pool.
+    /// CHECK: The address is verified using seeds. The marker need not exist for permissionless incentives.
+    #[account(seeds = [b"AllowedPool".as_ref(), pool.key().as_ref()], bump)]
+    pub allowed_pool: UncheckedAccount<'info>,
+
+    /// The allowlist marker of the reward token.
+    /// CHECK: The address is verified using seeds. The marker need not exist for permissionless incentives.
+    #[account(seeds = [b"AllowedMint".as_ref(), reward_token.key().as_ref()], bump)]
+    pub allowed_reward_token: UncheckedAccount<'info>,
+
+    /// Payer of the initialization, who creates the incentive.
+    #[account(
+        mut,
+        constraint = config.can_create_incentive(payer.key) @ ErrorCode::NotIncentiveCreator
+    )]
     pub payer: Signer<'info>,
 
     /// System program.
     pub system_program: Program<'info, System>,
 
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+
+    /// Rent sysvar.
+    pub rent: Sysvar<'info, Rent>,
+
     /// The locker to calculate boost.
     pub locker: Account<'info, Locker>,
 }
@@ -51,31 +99,52 @@ impl<'info> CreateIncentiveBoosted<'info> {
     ///
     /// * `start_time`- The time when the incentive program begins.
     /// * `end_time` - The time when rewards stop accruing.
-    /// * `reward` - The amount of reward tokens to be distributed.
+    /// * `boost_base_percent` - The percentage of staked liquidity which earns rewards regardless of voting power.
+    /// * `boost_denominator` - The total voting power against which the voting power of a staker is measured.
+    /// * `emission_schedule` - The emission schedule, empty for uniform emissions.
+    /// * `permissionless` - Whether the incentive was created without being vetted by the allowlists.
     ///
     pub fn create_incentive_boosted(
         &mut self,
         bump: u8,
         start_time: i64,
         end_time: i64,
+        boost_base_percent: u8,
+        boost_denominator: BoostDenominator,
+        emission_schedule: &[EmissionPoint],
+        permissionless: bool,
     ) -> Result<()> {
         let incentive = &mut self.incentive;
 
         incentive.bump = bump;
-        incentive.reward_token = self.reward_token.key();
+        incentive.rewards[0] = IncentiveReward {
+            mint: self.reward_token.key(),
+            total_reward_unclaimed: 0,
+            lock_bonus_reserve: 0,
+        };
         incentive.pool = self.pool.key();
         incentive.refundee = self.refundee.key();
+        incentive.authority = self.authority.key();
         incentive.start_time = start_time;
         incentive.end_time = end_time;
+        incentive.set_emission_schedule(emission_schedule)?;
+        incentive.payer = self.payer.key();
+        incentive.permissionless = permissionless;
         incentive.boost_locker = Some(self.locker.key());
+        incentive.boost_base_percent = boost_base_percent;
+        incentive.boost_denominator = boost_denominator;
 
         emit!(IncentiveCreatedEvent {
-            reward_token: incentive.reward_token,
+            reward_token: self.reward_token.key(),
             pool: incentive.pool,
             refundee: incentive.refundee,
+            authority: incentive.authority,
             start_time: incentive.start_time,
             end_time: incentive.end_time,
             boost_locker: incentive.boost_locker,
+            boost_base_percent: incentive.boost_base_percent,
+            boost_denominator: incentive.boost_denominator,
+            permissionless: incentive.permissionless,
         });
 
         Ok(())
@@ -1,3 +1,4 @@
+use crate::ErrorCode;
 use crate::*;
 use std::mem::size_of;
 
@@ -9,6 +10,7 @@ pub struct CreateRewardAccount<'info> {
         init,
         seeds = [
             b"Reward".as_ref(),
+            incentive.key().as_ref(),
             reward_token.key().as_ref(),
             reward_owner.key().as_ref()
         ],
@@ -18,8 +20,12 @@ pub struct CreateRewardAccount<'info> {
     )]
     pub reward: Account<'info, Reward>,
 
+    /// The [Incentive] paying out the reward.
+    pub incentive: Account<'info, Incentive>,
+
     /// The mint address of token being distributed as a reward.
-    /// CHECK: Allow lazy initialization of token mints.
+    /// CHECK: The address is verified against the incentive.
+    #[account(constraint = incentive.reward_index(reward_token.key).is_some() @ ErrorCode::InvalidRewardToken)]
     pub reward_token: UncheckedAccount<'info>,
 
     /// The address whose reward is tracked.
@@ -35,13 +41,15 @@ pub struct CreateRewardAccount<'info> {
 }
 
 impl<'info> CreateRewardAccount<'info> {
-    /// Creates an empty [Reward] account for a given token and address.
+    /// Creates an empty [Reward] account for a given incentive, token and address.
     pub fn create_reward_account(&mut self, bump: u8) -> Result<()> {
         let reward = &mut self.reward;
         reward.bump = bump;
+        reward.incentive = self.incentive.key();
         reward.reward_token = self.reward_token.key();
         reward.owner = self.reward_owner.key();
         reward.rewards_owed = 0;
+        reward.vesting = [VestingTranche::default(); MAX_VESTING_TRANCHES];
 
         Ok(())
     }
@@ -0,0 +1,186 @@
+use crate::ErrorCode;
+use crate::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use cyclos_core::cpi::accounts::DecreaseLiquidity as CoreDecreaseLiquidity;
+use cyclos_core::states::oracle::{ObservationState, OBSERVATION_SEED};
+use cyclos_core::states::pool::SnapshotCumulative;
+use cyclos_core::states::tick::TickState;
+use cyclos_core::states::tick::TICK_SEED;
+use cyclos_core::states::tokenized_position::TokenizedPositionState;
+use std::ops::Deref;
+
+/// Accounts for [cykura_staker::decrease_liquidity].
+#[derive(Accounts)]
+pub struct DecreaseLiquidity<'info> {
+    /// [Deposit] whose position liquidity is decreased.
+    #[account(has_one = owner @ErrorCode::OnlyOwnerCanModifyLiquidity)]
+    pub deposit: Account<'info, Deposit>,
+
+    /// The owner of the deposit.
+    pub owner: Signer<'info>,
+
+    /// The vault which holds the deposited token.
+    #[account(address = get_associated_token_address(stake_manager.key, &deposit.mint))]
+    pub deposit_vault: Account<'info, TokenAccount>,
+
+    /// The root program account which owns the deposited token.
+    /// CHECK: The address is verified using seeds and bump.
+    #[account(seeds = [], bump)]
+    pub stake_manager: UncheckedAccount<'info>,
+
+    /// The Cykura core program.
+    /// CHECK: The address is verified against the core program ID.
+    #[account(address = cyclos_core::ID)]
+    pub core_program: UncheckedAccount<'info>,
+
+    /// The account having metadata of the Cykura Position NFT.
+    #[account(mut, constraint = tokenized_position.load()?.mint == deposit.mint)]
+    pub tokenized_position: AccountLoader<'info, TokenizedPositionState>,
+
+    /// The core factory, which owns the core position.
+    /// CHECK: Verified by the core program.
+    pub factory_state: UncheckedAccount<'info>,
+
+    /// The liquidity pool to which the LP position belongs.
+    #[account(mut, address = tokenized_position.load()?.pool_id)]
+    pub pool: AccountLoader<'info, PoolState>,
+
+    /// The core position backing the position NFT.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub core_position: UncheckedAccount<'info>,
+
+    /// The lower tick account of the position.
+    #[account(
+        mut,
+        address = Pubkey::create_program_address(&[
+            TICK_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &deposit.tick_lower.to_be_bytes(),
+            &[tick_lower.load()?.bump]
+        ], &cyclos_core::ID).unwrap()
+    )]
+    pub tick_lower: AccountLoader<'info, TickState>,
+
+    /// The upper tick account of the position.
+    #[account(
+        mut,
+        address = Pubkey::create_program_address(&[
+            TICK_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &deposit.tick_upper.to_be_bytes(),
+            &[tick_upper.load()?.bump]
+        ], &cyclos_core::ID).unwrap()
+    )]
+    pub tick_upper: AccountLoader<'info, TickState>,
+
+    /// The bitmap of the lower tick.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub bitmap_lower: UncheckedAccount<'info>,
+
+    /// The bitmap of the upper tick.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub bitmap_upper: UncheckedAccount<'info>,
+
+    /// The latest oracle observation for the pool.
+    #[account(
+        mut,
+        address = Pubkey::create_program_address(&[
+            &OBSERVATION_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &pool.load()?.observation_index.to_be_bytes(),
+            &[latest_observation.load()?.bump]
+        ], &cyclos_core::ID).unwrap() @ErrorCode::NotLatestObservation,
+    )]
+    pub latest_observation: AccountLoader<'info, ObservationState>,
+
+    /// The next oracle observation for the pool.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub next_observation: UncheckedAccount<'info>,
+}
+
+impl<'info> DecreaseLiquidity<'info> {
+    /// Removes liquidity from a deposited position, settling the rewards of its stakes at the
+    /// previous liquidity. The withdrawn tokens are collected with [cykura_staker::collect_fees].
+    ///
+    /// # Arguments
+    ///
+    /// * `liquidity` - The amount of liquidity to remove.
+    /// * `amount_0_minimum` - The minimum amount of token_0 to receive.
+    /// * `amount_1_minimum` - The minimum amount of token_1 to receive.
+    /// * `deadline` - The time by which the transaction must be included.
+    /// * `remaining_accounts` - The stakes of the deposit, see [rewards::settle_stakes].
+    ///
+    pub fn decrease_liquidity(
+        &mut self,
+        bump: u8,
+        liquidity: u64,
+        amount_0_minimum: u64,
+        amount_1_minimum: u64,
+        deadline: i64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let block_timestamp = Clock::get().unwrap().unix_timestamp;
+
+        let SnapshotCumulative {
+            seconds_per_liquidity_inside_x32,
+            ..
+        } = self.pool.load()?.snapshot_cumulatives_inside(
+            self.tick_lower.load()?.deref(),
+            self.tick_upper.load()?.deref(),
+            self.latest_observation.load()?.deref(),
+        );
+
+        let seeds: [&[u8]; 1] = [&[bump]];
+        cyclos_core::cpi::decrease_liquidity(
+            CpiContext::new_with_signer(
+                self.core_program.to_account_info(),
+                CoreDecreaseLiquidity {
+                    owner_or_delegate: self.stake_manager.to_account_info(),
+                    nft_account: self.deposit_vault.to_account_info(),
+                    tokenized_position_state: self.tokenized_position.to_account_info(),
+                    factory_state: self.factory_state.to_account_info(),
+                    pool_state: self.pool.to_account_info(),
+                    core_position_state: self.core_position.to_account_info(),
+                    tick_lower_state: self.tick_lower.to_account_info(),
+                    tick_upper_state: self.tick_upper.to_account_info(),
+                    bitmap_lower_state: self.bitmap_lower.to_account_info(),
+                    bitmap_upper_state: self.bitmap_upper.to_account_info(),
+                    latest_observation_state: self.latest_observation.to_account_info(),
+                    next_observation_state: self.next_observation.to_account_info(),
+                    core_program: self.core_program.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            liquidity,
+            amount_0_minimum,
+            amount_1_minimum,
+            deadline,
+        )?;
+
+        let liquidity = self.tokenized_position.load()?.liquidity;
+        rewards::settle_stakes(
+            &self.deposit,
+            liquidity,
+            seconds_per_liquidity_inside_x32,
+            block_timestamp,
+            remaining_accounts,
+        )?;
+
+        emit!(ModifyLiquidityEvent {
+            mint: self.deposit.mint,
+            liquidity,
+        });
+
+        Ok(())
+    }
+}
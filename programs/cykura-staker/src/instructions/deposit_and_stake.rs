@@ -0,0 +1,96 @@
+use crate::ErrorCode;
+use crate::*;
+use cyclos_core::states::oracle::{ObservationState, OBSERVATION_SEED};
+use cyclos_core::states::pool::SnapshotCumulative;
+use cyclos_core::states::tick::TickState;
+use cyclos_core::states::tick::TICK_SEED;
+use std::ops::Deref;
+
+/// Accounts for [cykura_staker::deposit_and_stake].
+#[derive(Accounts)]
+pub struct DepositAndStake<'info> {
+    /// The accounts depositing the position NFT.
+    pub create_deposit: CreateDeposit<'info>,
+
+    /// The program [Config].
+    #[account(seeds = [b"Config".as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// The liquidity pool to which the LP position belongs.
+    #[account(
+        address = create_deposit.tokenized_position.load()?.pool_id,
+        constraint = create_deposit.tokenized_position.load()?.liquidity > 0 @ErrorCode::CannotStakeTokenWithZeroLiquidity,
+    )]
+    pub pool: AccountLoader<'info, PoolState>,
+
+    /// The lower tick account of the position.
+    #[account(
+        address = Pubkey::create_program_address(&[
+            TICK_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &create_deposit.tokenized_position.load()?.tick_lower.to_be_bytes(),
+            &[tick_lower.load()?.bump]
+        ], &cyclos_core::ID).unwrap()
+    )]
+    pub tick_lower: AccountLoader<'info, TickState>,
+
+    /// The upper tick account of the position.
+    #[account(
+        address = Pubkey::create_program_address(&[
+            TICK_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &create_deposit.tokenized_position.load()?.tick_upper.to_be_bytes(),
+            &[tick_upper.load()?.bump]
+        ], &cyclos_core::ID).unwrap()
+    )]
+    pub tick_upper: AccountLoader<'info, TickState>,
+
+    /// The latest oracle observation for the pool.
+    #[account(
+        address = Pubkey::create_program_address(&[
+            &OBSERVATION_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &pool.load()?.observation_index.to_be_bytes(),
+            &[latest_observation.load()?.bump]
+        ], &cyclos_core::ID).unwrap() @ErrorCode::NotLatestObservation,
+    )]
+    pub latest_observation: AccountLoader<'info, ObservationState>,
+}
+
+impl<'info> DepositAndStake<'info> {
+    /// Creates a new [Deposit] as [CreateDeposit::create_deposit], then stakes it in every
+    /// incentive passed in the remaining accounts, see [rewards::create_stakes].
+    pub fn deposit_and_stake(
+        &mut self,
+        bump: u8,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        self.create_deposit.create_deposit(bump)?;
+
+        let SnapshotCumulative {
+            seconds_per_liquidity_inside_x32,
+            ..
+        } = self.pool.load()?.snapshot_cumulatives_inside(
+            self.tick_lower.load()?.deref(),
+            self.tick_upper.load()?.deref(),
+            self.latest_observation.load()?.deref(),
+        );
+
+        let liquidity = self.create_deposit.tokenized_position.load()?.liquidity;
+        rewards::create_stakes(
+            &mut self.create_deposit.deposit,
+            self.pool.key(),
+            &self.create_deposit.depositor.to_account_info(),
+            &self.create_deposit.system_program.to_account_info(),
+            liquidity,
+            seconds_per_liquidity_inside_x32,
+            remaining_accounts,
+        )
+    }
+}
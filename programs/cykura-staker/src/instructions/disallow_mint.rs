@@ -0,0 +1,30 @@
+use crate::*;
+use anchor_lang::AccountsClose;
+
+/// Accounts for [cykura_staker::disallow_mint].
+#[derive(Accounts)]
+pub struct DisallowMint<'info> {
+    /// [AllowedMint]
+    #[account(mut)]
+    pub allowed_mint: Account<'info, AllowedMint>,
+
+    /// [Config]
+    #[account(has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    /// The admin of the program.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> DisallowMint<'info> {
+    /// Removes a reward token from the allowlist. Existing incentives of the token are unaffected.
+    pub fn disallow_mint(&mut self) -> Result<()> {
+        emit!(MintAllowedEvent {
+            mint: self.allowed_mint.mint,
+            allowed: false,
+        });
+
+        self.allowed_mint.close(self.admin.to_account_info())
+    }
+}
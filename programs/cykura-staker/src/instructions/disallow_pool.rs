@@ -0,0 +1,30 @@
+use crate::*;
+use anchor_lang::AccountsClose;
+
+/// Accounts for [cykura_staker::disallow_pool].
+#[derive(Accounts)]
+pub struct DisallowPool<'info> {
+    /// [AllowedPool]
+    #[account(mut)]
+    pub allowed_pool: Account<'info, AllowedPool>,
+
+    /// [Config]
+    #[account(has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    /// The admin of the program.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> DisallowPool<'info> {
+    /// Removes a pool from the allowlist. Existing incentives of the pool are unaffected.
+    pub fn disallow_pool(&mut self) -> Result<()> {
+        emit!(PoolAllowedEvent {
+            pool: self.allowed_pool.pool,
+            allowed: false,
+        });
+
+        self.allowed_pool.close(self.admin.to_account_info())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::ErrorCode;
+use crate::*;
+use anchor_lang::{AccountsClose, AccountsExit};
+
+/// Accounts for [cykura_staker::emergency_withdraw].
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    /// [Deposit] to unstake from every incentive.
+    #[account(mut, has_one = owner @ErrorCode::OnlyOwnerCanWithdrawToken)]
+    pub deposit: Account<'info, Deposit>,
+
+    /// The owner of the deposit, who receives the rent of the closed stakes.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+impl<'info> EmergencyWithdraw<'info> {
+    /// Closes every [Stake] of a deposit without settling rewards. Forfeited rewards, including
+    /// those held back by the minimum stake duration, stay with the incentive.
+    ///
+    /// This does not read the pool, so positions can be recovered even if reward accrual fails.
+    pub fn emergency_withdraw(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let deposit = &mut self.deposit;
+        require!(
+            remaining_accounts.len() == 2 * deposit.number_of_stakes as usize,
+            ErrorCode::MissingStakeAccount
+        );
+
+        for accounts in remaining_accounts.chunks(2) {
+            let stake = Account::<Stake>::try_from(&accounts[0])?;
+            let mut incentive = Account::<Incentive>::try_from(&accounts[1])?;
+            require!(
+                stake.mint == deposit.mint && stake.incentive == incentive.key(),
+                ErrorCode::InvalidStakeAccount
+            );
+
+            deposit.number_of_stakes -= 1;
+            incentive.number_of_stakes -= 1;
+            incentive.total_staked_voting_power -= stake.voting_power;
+            for (slot, pending) in incentive.rewards.iter_mut().zip(stake.pending_rewards) {
+                slot.total_reward_unclaimed += pending;
+            }
+            incentive.exit(&crate::id())?;
+
+            stake.close(self.owner.to_account_info())?;
+
+            emit!(UnstakeTokenEvent {
+                mint: deposit.mint,
+                incentive: incentive.key()
+            });
+        }
+
+        emit!(EmergencyWithdrawEvent {
+            mint: deposit.mint,
+            owner: deposit.owner,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when a deposit has been force-unstaked from every incentive.
+pub struct EmergencyWithdrawEvent {
+    /// The unique identifier of a Cykura LP token.
+    #[index]
+    pub mint: Pubkey,
+
+    /// The owner of the deposit.
+    pub owner: Pubkey,
+}
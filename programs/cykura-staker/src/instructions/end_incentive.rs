@@ -1,18 +1,24 @@
+use crate::ErrorCode;
 use crate::*;
-use anchor_spl::associated_token::get_associated_token_address;
+use anchor_lang::AccountsClose;
 use anchor_spl::token;
 
 /// Accounts for [cykura_staker::end_incentive].
 #[derive(Accounts)]
 pub struct EndIncentive<'info> {
     /// [Incentive] to end.
-    #[account(mut)]
+    #[account(mut, has_one = payer)]
     pub incentive: Account<'info, Incentive>,
 
-    /// The incentive token account which will make the refund.
+    /// The incentive token account which will make the refund of the first reward token.
     #[account(
         mut,
-        address = get_associated_token_address(stake_manager.key, &incentive.reward_token)
+        seeds = [
+            b"Vault".as_ref(),
+            incentive.key().as_ref(),
+            incentive.rewards[0].mint.as_ref()
+        ],
+        bump
     )]
     pub vault: Account<'info, TokenAccount>,
 
@@ -21,48 +27,145 @@ pub struct EndIncentive<'info> {
     #[account(seeds = [], bump)]
     pub stake_manager: UncheckedAccount<'info>,
 
-    /// The token account of the refundee.
-    #[account(mut, constraint = refundee_token_account.owner == incentive.refundee)]
+    /// The token account of the refundee for the first reward token.
+    #[account(
+        mut,
+        constraint = refundee_token_account.owner == incentive.refundee,
+        constraint = refundee_token_account.mint == incentive.rewards[0].mint
+    )]
     // owner field is bugged in v0.22
     pub refundee_token_account: Account<'info, TokenAccount>,
 
+    /// The account which paid rent for the incentive, receiving it back on close.
+    /// CHECK: The address is verified against the incentive.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
     /// Token program.
     pub token_program: Program<'info, Token>,
 }
 
 impl<'info> EndIncentive<'info> {
     /// Ends an [Incentive] after the incentive end time has passed and all stakes have been withdrawn
-    pub fn end_incentive(&mut self, bump: u8) -> Result<()> {
-        let incentive = &mut self.incentive;
+    ///
+    /// The vault and refundee token account of every reward token other than the first are
+    /// passed in pairs as remaining accounts.
+    pub fn end_incentive(
+        &mut self,
+        bump: u8,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let reward_token_count = self.incentive.reward_token_count();
+        require!(
+            remaining_accounts.len() >= 2 * (reward_token_count - 1),
+            ErrorCode::MissingRewardAccount
+        );
 
-        let refund = incentive.total_reward_unclaimed;
+        let mut refunds = [0; MAX_REWARD_TOKENS];
+        let mut rent = self.incentive.to_account_info().lamports();
 
-        // issue the refund
-        let seeds: [&[u8]; 1] = [&[bump]];
-        token::transfer(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                token::Transfer {
-                    from: self.vault.to_account_info(),
-                    to: self.vault.to_account_info(),
-                    authority: self.stake_manager.to_account_info(),
-                },
-                &[&seeds[..]],
-            ),
-            refund,
+        let IncentiveReward {
+            total_reward_unclaimed,
+            lock_bonus_reserve,
+            ..
+        } = self.incentive.rewards[0];
+        refunds[0] = total_reward_unclaimed + lock_bonus_reserve;
+        rent += self.refund(
+            &self.vault.to_account_info(),
+            &self.refundee_token_account.to_account_info(),
+            refunds[0],
+            bump,
         )?;
 
-        incentive.total_reward_unclaimed = 0;
+        for index in 1..reward_token_count {
+            let mint = self.incentive.rewards[index].mint;
+            let vault = &remaining_accounts[2 * (index - 1)];
+            let refundee_token_account = &remaining_accounts[2 * (index - 1) + 1];
+
+            let (vault_address, _) = Pubkey::find_program_address(
+                &[
+                    b"Vault".as_ref(),
+                    self.incentive.key().as_ref(),
+                    mint.as_ref(),
+                ],
+                &crate::id(),
+            );
+            require!(vault.key() == vault_address, ErrorCode::InvalidVault);
+            let refundee_account = Account::<TokenAccount>::try_from(refundee_token_account)?;
+            require!(
+                refundee_account.owner == self.incentive.refundee && refundee_account.mint == mint,
+                ErrorCode::InvalidRefundeeTokenAccount
+            );
+
+            let IncentiveReward {
+                total_reward_unclaimed,
+                lock_bonus_reserve,
+                ..
+            } = self.incentive.rewards[index];
+            refunds[index] = total_reward_unclaimed + lock_bonus_reserve;
+            rent += self.refund(vault, refundee_token_account, refunds[index], bump)?;
+        }
 
-        // note we never clear total_seconds_claimed_x32
+        for reward in self.incentive.rewards.iter_mut() {
+            reward.total_reward_unclaimed = 0;
+            reward.lock_bonus_reserve = 0;
+        }
 
         emit!(EndIncentiveEvent {
-            incentive: incentive.key(),
-            refund
+            incentive: self.incentive.key(),
+            refunds,
+            rent,
         });
 
+        self.incentive.close(self.payer.to_account_info())?;
+
         Ok(())
     }
+
+    /// Refunds a reward token to the refundee, and closes the vault if no rewards are left to claim
+    /// from it. Returns the lamports recovered from the vault.
+    fn refund(
+        &self,
+        vault: &AccountInfo<'info>,
+        refundee_token_account: &AccountInfo<'info>,
+        refund: u64,
+        bump: u8,
+    ) -> Result<u64> {
+        let seeds: [&[u8]; 1] = [&[bump]];
+
+        // issue the refund
+        if refund > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: vault.clone(),
+                        to: refundee_token_account.clone(),
+                        authority: self.stake_manager.to_account_info(),
+                    },
+                    &[&seeds[..]],
+                ),
+                refund,
+            )?;
+        }
+
+        // the vault stays open while users have rewards left to claim from it
+        if Account::<TokenAccount>::try_from(vault)?.amount > 0 {
+            return Ok(0);
+        }
+        let rent = vault.lamports();
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            token::CloseAccount {
+                account: vault.clone(),
+                destination: self.payer.to_account_info(),
+                authority: self.stake_manager.to_account_info(),
+            },
+            &[&seeds[..]],
+        ))?;
+
+        Ok(rent)
+    }
 }
 
 #[event]
@@ -71,6 +174,9 @@ pub struct EndIncentiveEvent {
     /// The incentive which is ending.
     pub incentive: Pubkey,
 
-    /// The amount of reward tokens refunded.
-    pub refund: u64,
+    /// The amount of every reward token refunded, including the unused lock bonus reserve.
+    pub refunds: [u64; MAX_REWARD_TOKENS],
+
+    /// The lamports returned to the payer by closing the incentive and its empty vaults.
+    pub rent: u64,
 }
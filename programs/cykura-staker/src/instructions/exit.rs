@@ -0,0 +1,84 @@
+use crate::ErrorCode;
+use crate::*;
+use anchor_lang::AccountsClose;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token;
+use rewards::RewardPayout;
+
+/// Accounts for [cykura_staker::exit].
+#[derive(Accounts)]
+pub struct Exit<'info> {
+    /// The accounts unstaking the deposit from every incentive.
+    pub unstake_all: UnstakeAll<'info>,
+
+    /// The vault which holds the deposited token.
+    #[account(
+        mut,
+        address = get_associated_token_address(stake_manager.key, &unstake_all.deposit.mint)
+    )]
+    pub deposit_vault: Account<'info, TokenAccount>,
+
+    /// The root program account which acts as the deposit and reward vault authority.
+    /// CHECK: The address is verified using seeds and bump.
+    #[account(seeds = [], bump)]
+    pub stake_manager: UncheckedAccount<'info>,
+
+    /// The address where the LP token will be sent
+    /// CHECK: The LP token can be transferred to any account. The transfer CPI ensures this is an
+    /// initialized token account.
+    #[account(mut, constraint = to.key() != deposit_vault.key() @ErrorCode::CannotWithdrawToStaker)]
+    pub to: UncheckedAccount<'info>,
+
+    /// The program [Config].
+    #[account(seeds = [b"Config".as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> Exit<'info> {
+    /// Unstakes a Cykura LP token from every incentive, pays out the rewards of the owner and
+    /// withdraws the token to the recipient `to`, closing the [Deposit].
+    ///
+    /// The remaining accounts are the stakes of the deposit as for [UnstakeAll::unstake_all],
+    /// followed by the reward accounts to claim as for [RewardPayout::pay_all].
+    pub fn exit(&mut self, bump: u8, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let reward_accounts = self.unstake_all.unstake_all(remaining_accounts)?;
+
+        RewardPayout {
+            config: &self.config,
+            stake_manager: self.stake_manager.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            bump,
+        }
+        .pay_all(&self.unstake_all.owner.key(), reward_accounts)?;
+
+        let deposit = &mut self.unstake_all.deposit;
+
+        let seeds: [&[u8]; 1] = [&[bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.deposit_vault.to_account_info(),
+                    to: self.to.to_account_info(),
+                    authority: self.stake_manager.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            1,
+        )?;
+
+        emit!(TransferDepositEvent {
+            deposit: deposit.key(),
+            mint: deposit.mint,
+            old_owner: deposit.owner,
+            new_owner: Pubkey::default(),
+        });
+
+        deposit.close(self.unstake_all.owner.to_account_info())?;
+
+        Ok(())
+    }
+}
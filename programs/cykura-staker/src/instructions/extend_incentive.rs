@@ -0,0 +1,114 @@
+use crate::ErrorCode;
+use crate::*;
+use anchor_spl::token;
+
+/// Accounts for [cykura_staker::extend_incentive].
+#[derive(Accounts)]
+pub struct ExtendIncentive<'info> {
+    /// [Incentive] to extend.
+    #[account(mut)]
+    pub incentive: Account<'info, Incentive>,
+
+    /// The authority or the refundee of the incentive.
+    #[account(
+        constraint = authority.key() == incentive.authority
+            || authority.key() == incentive.refundee
+            @ ErrorCode::OnlyAuthorityCanManageIncentive
+    )]
+    pub authority: Signer<'info>,
+
+    /// The program [Config].
+    #[account(seeds = [b"Config".as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// The vault to hold tokens of the reward being added.
+    #[account(
+        mut,
+        seeds = [
+            b"Vault".as_ref(),
+            incentive.key().as_ref(),
+            vault.mint.as_ref()
+        ],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// The account paying the incentive reward.
+    pub payer: Signer<'info>,
+
+    /// The token account of the payer.
+    /// CHECK: mint and signer are validated in the CPI.
+    #[account(mut)]
+    pub payer_token_account: UncheckedAccount<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ExtendIncentive<'info> {
+    /// Pushes out the end time of an [Incentive] and tops up its rewards.
+    ///
+    /// The incentive address remains derived from the original end time.
+    ///
+    /// # Arguments
+    ///
+    /// * `end_time` - The new time when rewards stop accruing.
+    /// * `reward` - The amount of reward tokens added to the incentive, which must be positive.
+    /// * `emission_points` - Points appended to the emission schedule for the extended period.
+    /// * `current_time` - The current block timestamp.
+    ///
+    pub fn extend_incentive(
+        &mut self,
+        end_time: i64,
+        reward: u64,
+        emission_points: &[EmissionPoint],
+        current_time: i64,
+    ) -> Result<()> {
+        let incentive = &mut self.incentive;
+        incentive.end_time = end_time;
+        incentive.extend_emission_schedule(emission_points, current_time)?;
+
+        let index = incentive
+            .reward_index(&self.vault.mint)
+            .ok_or(ErrorCode::InvalidRewardToken)?;
+        incentive.rewards[index].total_reward_unclaimed += reward;
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.payer_token_account.to_account_info(),
+                    to: self.vault.to_account_info(),
+                    authority: self.payer.to_account_info(),
+                },
+            ),
+            reward,
+        )?;
+
+        emit!(ExtendIncentiveEvent {
+            incentive: incentive.key(),
+            end_time,
+            reward_token: self.vault.mint,
+            reward,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when an [Incentive] has been extended.
+pub struct ExtendIncentiveEvent {
+    /// [Incentive] address.
+    #[index]
+    pub incentive: Pubkey,
+
+    /// The new time when rewards stop accruing.
+    pub end_time: i64,
+
+    /// The token being added as a reward.
+    pub reward_token: Pubkey,
+
+    /// The reward amount added.
+    pub reward: u64,
+}
@@ -0,0 +1,214 @@
+use crate::ErrorCode;
+use crate::*;
+use cyclos_core::cpi::accounts::IncreaseLiquidity as CoreIncreaseLiquidity;
+use cyclos_core::states::oracle::{ObservationState, OBSERVATION_SEED};
+use cyclos_core::states::pool::SnapshotCumulative;
+use cyclos_core::states::tick::TickState;
+use cyclos_core::states::tick::TICK_SEED;
+use cyclos_core::states::tokenized_position::TokenizedPositionState;
+use std::ops::Deref;
+
+/// Accounts for [cykura_staker::increase_liquidity].
+#[derive(Accounts)]
+pub struct IncreaseLiquidity<'info> {
+    /// [Deposit] whose position liquidity is increased.
+    #[account(has_one = owner @ErrorCode::OnlyOwnerCanModifyLiquidity)]
+    pub deposit: Account<'info, Deposit>,
+
+    /// The owner of the deposit, who pays the added tokens.
+    pub owner: Signer<'info>,
+
+    /// The token account of the owner paying token_0.
+    /// CHECK: Mint and signer are validated in the CPI.
+    #[account(mut)]
+    pub token_account_0: UncheckedAccount<'info>,
+
+    /// The token account of the owner paying token_1.
+    /// CHECK: Mint and signer are validated in the CPI.
+    #[account(mut)]
+    pub token_account_1: UncheckedAccount<'info>,
+
+    /// The Cykura core program.
+    /// CHECK: The address is verified against the core program ID.
+    #[account(address = cyclos_core::ID)]
+    pub core_program: UncheckedAccount<'info>,
+
+    /// The account having metadata of the Cykura Position NFT.
+    #[account(mut, constraint = tokenized_position.load()?.mint == deposit.mint)]
+    pub tokenized_position: AccountLoader<'info, TokenizedPositionState>,
+
+    /// The core factory, which owns the core position.
+    /// CHECK: Verified by the core program.
+    pub factory_state: UncheckedAccount<'info>,
+
+    /// The liquidity pool to which the LP position belongs.
+    #[account(mut, address = tokenized_position.load()?.pool_id)]
+    pub pool: AccountLoader<'info, PoolState>,
+
+    /// The core position backing the position NFT.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub core_position: UncheckedAccount<'info>,
+
+    /// The lower tick account of the position.
+    #[account(
+        mut,
+        address = Pubkey::create_program_address(&[
+            TICK_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &deposit.tick_lower.to_be_bytes(),
+            &[tick_lower.load()?.bump]
+        ], &cyclos_core::ID).unwrap()
+    )]
+    pub tick_lower: AccountLoader<'info, TickState>,
+
+    /// The upper tick account of the position.
+    #[account(
+        mut,
+        address = Pubkey::create_program_address(&[
+            TICK_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &deposit.tick_upper.to_be_bytes(),
+            &[tick_upper.load()?.bump]
+        ], &cyclos_core::ID).unwrap()
+    )]
+    pub tick_upper: AccountLoader<'info, TickState>,
+
+    /// The bitmap of the lower tick.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub bitmap_lower: UncheckedAccount<'info>,
+
+    /// The bitmap of the upper tick.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub bitmap_upper: UncheckedAccount<'info>,
+
+    /// The latest oracle observation for the pool.
+    #[account(
+        mut,
+        address = Pubkey::create_program_address(&[
+            &OBSERVATION_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &pool.load()?.observation_index.to_be_bytes(),
+            &[latest_observation.load()?.bump]
+        ], &cyclos_core::ID).unwrap() @ErrorCode::NotLatestObservation,
+    )]
+    pub latest_observation: AccountLoader<'info, ObservationState>,
+
+    /// The next oracle observation for the pool.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub next_observation: UncheckedAccount<'info>,
+
+    /// The pool vault of token_0.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub vault_0: UncheckedAccount<'info>,
+
+    /// The pool vault of token_1.
+    /// CHECK: Verified by the core program.
+    #[account(mut)]
+    pub vault_1: UncheckedAccount<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> IncreaseLiquidity<'info> {
+    /// Adds liquidity to a deposited position, settling the rewards of its stakes at the previous
+    /// liquidity.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_0_desired` - The desired amount of token_0 to be spent.
+    /// * `amount_1_desired` - The desired amount of token_1 to be spent.
+    /// * `amount_0_minimum` - The minimum amount of token_0 to spend.
+    /// * `amount_1_minimum` - The minimum amount of token_1 to spend.
+    /// * `deadline` - The time by which the transaction must be included.
+    /// * `remaining_accounts` - The stakes of the deposit, see [rewards::settle_stakes].
+    ///
+    pub fn increase_liquidity(
+        &mut self,
+        amount_0_desired: u64,
+        amount_1_desired: u64,
+        amount_0_minimum: u64,
+        amount_1_minimum: u64,
+        deadline: i64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let block_timestamp = Clock::get().unwrap().unix_timestamp;
+
+        let SnapshotCumulative {
+            seconds_per_liquidity_inside_x32,
+            ..
+        } = self.pool.load()?.snapshot_cumulatives_inside(
+            self.tick_lower.load()?.deref(),
+            self.tick_upper.load()?.deref(),
+            self.latest_observation.load()?.deref(),
+        );
+
+        cyclos_core::cpi::increase_liquidity(
+            CpiContext::new(
+                self.core_program.to_account_info(),
+                CoreIncreaseLiquidity {
+                    payer: self.owner.to_account_info(),
+                    factory_state: self.factory_state.to_account_info(),
+                    pool_state: self.pool.to_account_info(),
+                    core_position_state: self.core_position.to_account_info(),
+                    tick_lower_state: self.tick_lower.to_account_info(),
+                    tick_upper_state: self.tick_upper.to_account_info(),
+                    bitmap_lower_state: self.bitmap_lower.to_account_info(),
+                    bitmap_upper_state: self.bitmap_upper.to_account_info(),
+                    token_account_0: self.token_account_0.to_account_info(),
+                    token_account_1: self.token_account_1.to_account_info(),
+                    vault_0: self.vault_0.to_account_info(),
+                    vault_1: self.vault_1.to_account_info(),
+                    latest_observation_state: self.latest_observation.to_account_info(),
+                    next_observation_state: self.next_observation.to_account_info(),
+                    tokenized_position_state: self.tokenized_position.to_account_info(),
+                    core_program: self.core_program.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                },
+            ),
+            amount_0_desired,
+            amount_1_desired,
+            amount_0_minimum,
+            amount_1_minimum,
+            deadline,
+        )?;
+
+        let liquidity = self.tokenized_position.load()?.liquidity;
+        rewards::settle_stakes(
+            &self.deposit,
+            liquidity,
+            seconds_per_liquidity_inside_x32,
+            block_timestamp,
+            remaining_accounts,
+        )?;
+
+        emit!(ModifyLiquidityEvent {
+            mint: self.deposit.mint,
+            liquidity,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when the liquidity of a deposited position has been increased or decreased.
+pub struct ModifyLiquidityEvent {
+    /// The unique identifier of a Cykura LP token.
+    #[index]
+    pub mint: Pubkey,
+
+    /// The new liquidity of the position.
+    pub liquidity: u64,
+}
@@ -0,0 +1,83 @@
+use crate::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use std::mem::size_of;
+
+/// Accounts for [cykura_staker::initialize_config].
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// [Config]
+    #[account(
+        init,
+        seeds = [b"Config".as_ref()],
+        bump,
+        payer = upgrade_authority,
+        space = 8 + size_of::<Config>()
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The program data account of the staker program.
+    #[account(
+        address = Pubkey::find_program_address(
+            &[crate::id().as_ref()],
+            &bpf_loader_upgradeable::id()
+        ).0,
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// The upgrade authority of the program.
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeConfig<'info> {
+    /// Initializes the [Config] with the default limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin` - The address which can update the configuration.
+    ///
+    pub fn initialize_config(&mut self, bump: u8, admin: Pubkey) -> Result<()> {
+        let config = &mut self.config;
+
+        config.bump = bump;
+        config.admin = admin;
+        config.pending_admin = None;
+        config.max_incentive_duration = MAX_INCENTIVE_DURATION;
+        config.max_incentive_start_lead_time = MAX_INCENTIVE_START_LEAD_TIME;
+        config.incentive_creator = None;
+        config.paused = false;
+        config.fee_bps = 0;
+        config.pending_fee_bps = None;
+        config.pending_fee_effective_at = 0;
+        config.treasury = admin;
+
+        emit!(ConfigUpdatedEvent {
+            admin: config.admin,
+            max_incentive_duration: config.max_incentive_duration,
+            max_incentive_start_lead_time: config.max_incentive_start_lead_time,
+            incentive_creator: config.incentive_creator,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when the [Config] has been initialized or updated.
+pub struct ConfigUpdatedEvent {
+    /// The address which can update the configuration.
+    pub admin: Pubkey,
+
+    /// The max duration of an incentive in seconds.
+    pub max_incentive_duration: i64,
+
+    /// The max amount of seconds into the future the incentive start_time can be set.
+    pub max_incentive_start_lead_time: i64,
+
+    /// The only address which can create incentives, if any.
+    pub incentive_creator: Option<Pubkey>,
+}
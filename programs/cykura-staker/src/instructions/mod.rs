@@ -1,27 +1,95 @@
 //! Instruction processors.
 
+pub mod accept_admin;
+pub mod add_lock_bonus;
 pub mod add_reward;
+pub mod add_reward_token;
+pub mod allow_mint;
+pub mod allow_pool;
+pub mod cancel_incentive;
+pub mod claim_and_lock;
 pub mod claim_reward;
+pub mod claim_rewards_multi;
+pub mod close_vault;
+pub mod collect_fees;
 pub mod create_deposit;
 pub mod create_incentive;
 pub mod create_incentive_boosted;
 pub mod create_reward_account;
+pub mod decrease_liquidity;
+pub mod deposit_and_stake;
+pub mod disallow_mint;
+pub mod disallow_pool;
+pub mod emergency_withdraw;
 pub mod end_incentive;
+pub mod exit;
+pub mod extend_incentive;
+pub mod harvest;
+pub mod increase_liquidity;
+pub mod initialize_config;
+pub mod poke_boost;
+pub mod set_allowlist_enabled;
+pub mod set_config;
+pub mod set_incentive_paused;
+pub mod set_lock_bonus;
+pub mod set_min_stake_duration;
+pub mod set_paused;
+pub mod set_protocol_fee;
+pub mod set_vesting_duration;
 pub mod stake_token;
+pub mod stake_token_boosted;
+pub mod stake_token_multi;
+pub mod sync_stake_liquidity;
+pub mod transfer_admin;
 pub mod transfer_deposit;
+pub mod unstake_all;
 pub mod unstake_token;
 pub mod unstake_token_boosted;
 pub mod withdraw_token;
 
+pub use accept_admin::*;
+pub use add_lock_bonus::*;
 pub use add_reward::*;
+pub use add_reward_token::*;
+pub use allow_mint::*;
+pub use allow_pool::*;
+pub use cancel_incentive::*;
+pub use claim_and_lock::*;
 pub use claim_reward::*;
+pub use claim_rewards_multi::*;
+pub use close_vault::*;
+pub use collect_fees::*;
 pub use create_deposit::*;
 pub use create_incentive::*;
 pub use create_incentive_boosted::*;
 pub use create_reward_account::*;
+pub use decrease_liquidity::*;
+pub use deposit_and_stake::*;
+pub use disallow_mint::*;
+pub use disallow_pool::*;
+pub use emergency_withdraw::*;
 pub use end_incentive::*;
+pub use exit::*;
+pub use extend_incentive::*;
+pub use harvest::*;
+pub use increase_liquidity::*;
+pub use initialize_config::*;
+pub use poke_boost::*;
+pub use set_allowlist_enabled::*;
+pub use set_config::*;
+pub use set_incentive_paused::*;
+pub use set_lock_bonus::*;
+pub use set_min_stake_duration::*;
+pub use set_paused::*;
+pub use set_protocol_fee::*;
+pub use set_vesting_duration::*;
 pub use stake_token::*;
+pub use stake_token_boosted::*;
+pub use stake_token_multi::*;
+pub use sync_stake_liquidity::*;
+pub use transfer_admin::*;
 pub use transfer_deposit::*;
+pub use unstake_all::*;
 pub use unstake_token::*;
 pub use unstake_token_boosted::*;
 pub use withdraw_token::*;
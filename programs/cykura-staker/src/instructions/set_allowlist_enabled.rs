@@ -0,0 +1,35 @@
+use crate::*;
+
+/// Accounts for [cykura_staker::set_allowlist_enabled].
+#[derive(Accounts)]
+pub struct SetAllowlistEnabled<'info> {
+    /// [Config]
+    #[account(mut, has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    /// The admin of the program.
+    pub admin: Signer<'info>,
+}
+
+impl<'info> SetAllowlistEnabled<'info> {
+    /// Enables or disables the allowlists.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether incentives must use an allowed pool and reward token, unless created as permissionless.
+    ///
+    pub fn set_allowlist_enabled(&mut self, enabled: bool) -> Result<()> {
+        self.config.allowlist_enabled = enabled;
+
+        emit!(AllowlistEnabledEvent { enabled });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when the allowlists have been enabled or disabled.
+pub struct AllowlistEnabledEvent {
+    /// Whether incentives must use an allowed pool and reward token, unless created as permissionless.
+    pub enabled: bool,
+}
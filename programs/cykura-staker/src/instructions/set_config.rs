@@ -0,0 +1,44 @@
+use crate::*;
+
+/// Accounts for [cykura_staker::set_config].
+#[derive(Accounts)]
+pub struct SetConfig<'info> {
+    /// [Config]
+    #[account(mut, has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    /// The admin of the program.
+    pub admin: Signer<'info>,
+}
+
+impl<'info> SetConfig<'info> {
+    /// Updates the limits of the [Config].
+    ///
+    /// # Arguments
+    ///
+    /// * `max_incentive_duration` - The max duration of an incentive in seconds.
+    /// * `max_incentive_start_lead_time` - The max amount of seconds into the future the incentive start_time can be set.
+    /// * `incentive_creator` - The only address which can create incentives, or none to allow anyone.
+    ///
+    pub fn set_config(
+        &mut self,
+        max_incentive_duration: i64,
+        max_incentive_start_lead_time: i64,
+        incentive_creator: Option<Pubkey>,
+    ) -> Result<()> {
+        let config = &mut self.config;
+
+        config.max_incentive_duration = max_incentive_duration;
+        config.max_incentive_start_lead_time = max_incentive_start_lead_time;
+        config.incentive_creator = incentive_creator;
+
+        emit!(ConfigUpdatedEvent {
+            admin: config.admin,
+            max_incentive_duration: config.max_incentive_duration,
+            max_incentive_start_lead_time: config.max_incentive_start_lead_time,
+            incentive_creator: config.incentive_creator,
+        });
+
+        Ok(())
+    }
+}
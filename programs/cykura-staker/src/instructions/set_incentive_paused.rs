@@ -0,0 +1,31 @@
+use crate::*;
+
+/// Accounts for [cykura_staker::set_incentive_paused].
+#[derive(Accounts)]
+pub struct SetIncentivePaused<'info> {
+    /// [Incentive] to pause or unpause.
+    #[account(mut, has_one = authority)]
+    pub incentive: Account<'info, Incentive>,
+
+    /// The authority of the incentive.
+    pub authority: Signer<'info>,
+}
+
+impl<'info> SetIncentivePaused<'info> {
+    /// Pauses or unpauses an [Incentive].
+    ///
+    /// # Arguments
+    ///
+    /// * `paused` - Whether staking and adding rewards are paused.
+    ///
+    pub fn set_incentive_paused(&mut self, paused: bool) -> Result<()> {
+        self.incentive.paused = paused;
+
+        emit!(PausedEvent {
+            incentive: Some(self.incentive.key()),
+            paused,
+        });
+
+        Ok(())
+    }
+}
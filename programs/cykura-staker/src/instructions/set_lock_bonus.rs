@@ -0,0 +1,42 @@
+use crate::*;
+
+/// Accounts for [cykura_staker::set_lock_bonus].
+#[derive(Accounts)]
+pub struct SetLockBonus<'info> {
+    /// The boosted [Incentive] offering the bonus.
+    #[account(mut, has_one = authority)]
+    pub incentive: Account<'info, Incentive>,
+
+    /// The authority of the incentive.
+    pub authority: Signer<'info>,
+}
+
+impl<'info> SetLockBonus<'info> {
+    /// Sets the bonus paid on rewards claimed into a lock with `claim_and_lock`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lock_bonus_bps` - The bonus in basis points of the amount claimed.
+    ///
+    pub fn set_lock_bonus(&mut self, lock_bonus_bps: u16) -> Result<()> {
+        self.incentive.lock_bonus_bps = lock_bonus_bps;
+
+        emit!(LockBonusUpdatedEvent {
+            incentive: self.incentive.key(),
+            lock_bonus_bps,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when the lock bonus of an [Incentive] has been updated.
+pub struct LockBonusUpdatedEvent {
+    /// The incentive offering the bonus.
+    #[index]
+    pub incentive: Pubkey,
+
+    /// The bonus in basis points of the amount claimed.
+    pub lock_bonus_bps: u16,
+}
@@ -0,0 +1,55 @@
+use crate::*;
+
+/// Accounts for [cykura_staker::set_min_stake_duration].
+#[derive(Accounts)]
+pub struct SetMinStakeDuration<'info> {
+    /// [Incentive] to configure.
+    #[account(mut, has_one = authority)]
+    pub incentive: Account<'info, Incentive>,
+
+    /// The authority of the incentive.
+    pub authority: Signer<'info>,
+}
+
+impl<'info> SetMinStakeDuration<'info> {
+    /// Sets the minimum stake duration of an [Incentive] and the penalty on rewards of stakes
+    /// removed before it has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_stake_duration` - The minimum stake duration in seconds.
+    /// * `early_unstake_penalty_bps` - The share of rewards forfeited back to the incentive, in
+    /// basis points. 10000 forfeits every reward.
+    ///
+    pub fn set_min_stake_duration(
+        &mut self,
+        min_stake_duration: i64,
+        early_unstake_penalty_bps: u16,
+    ) -> Result<()> {
+        let incentive = &mut self.incentive;
+        incentive.min_stake_duration = min_stake_duration;
+        incentive.early_unstake_penalty_bps = early_unstake_penalty_bps;
+
+        emit!(MinStakeDurationUpdatedEvent {
+            incentive: incentive.key(),
+            min_stake_duration,
+            early_unstake_penalty_bps,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when the minimum stake duration of an [Incentive] has been updated.
+pub struct MinStakeDurationUpdatedEvent {
+    /// The incentive configured.
+    #[index]
+    pub incentive: Pubkey,
+
+    /// The minimum stake duration in seconds.
+    pub min_stake_duration: i64,
+
+    /// The share of rewards forfeited when unstaking early, in basis points.
+    pub early_unstake_penalty_bps: u16,
+}
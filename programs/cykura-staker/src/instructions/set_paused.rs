@@ -0,0 +1,41 @@
+use crate::*;
+
+/// Accounts for [cykura_staker::set_paused].
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    /// [Config]
+    #[account(mut, has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    /// The admin of the program.
+    pub admin: Signer<'info>,
+}
+
+impl<'info> SetPaused<'info> {
+    /// Pauses or unpauses the program.
+    ///
+    /// # Arguments
+    ///
+    /// * `paused` - Whether staking, adding rewards and claiming rewards are paused.
+    ///
+    pub fn set_paused(&mut self, paused: bool) -> Result<()> {
+        self.config.paused = paused;
+
+        emit!(PausedEvent {
+            incentive: None,
+            paused,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when the program or an [Incentive] has been paused or unpaused.
+pub struct PausedEvent {
+    /// The paused [Incentive], or none if the whole program is paused.
+    pub incentive: Option<Pubkey>,
+
+    /// Whether staking, adding rewards and claiming rewards are paused.
+    pub paused: bool,
+}
@@ -0,0 +1,65 @@
+use crate::*;
+
+/// Accounts for [cykura_staker::set_protocol_fee].
+#[derive(Accounts)]
+pub struct SetProtocolFee<'info> {
+    /// [Config]
+    #[account(mut, has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    /// The admin of the program.
+    pub admin: Signer<'info>,
+}
+
+impl<'info> SetProtocolFee<'info> {
+    /// Sets the protocol fee and treasury. A lower fee applies immediately, while a higher fee
+    /// takes effect after `PROTOCOL_FEE_TIMELOCK` so that stakers can claim at the current fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `fee_bps` - The protocol fee taken from claimed rewards, in basis points.
+    /// * `treasury` - The wallet whose token accounts receive the protocol fee.
+    /// * `current_time` - The current block timestamp.
+    ///
+    pub fn set_protocol_fee(
+        &mut self,
+        fee_bps: u16,
+        treasury: Pubkey,
+        current_time: i64,
+    ) -> Result<()> {
+        let config = &mut self.config;
+
+        let current_fee_bps = config.fee_bps_at(current_time);
+        if fee_bps <= current_fee_bps {
+            config.fee_bps = fee_bps;
+            config.pending_fee_bps = None;
+            config.pending_fee_effective_at = current_time;
+        } else {
+            config.fee_bps = current_fee_bps;
+            config.pending_fee_bps = Some(fee_bps);
+            config.pending_fee_effective_at = current_time + PROTOCOL_FEE_TIMELOCK;
+        }
+        config.treasury = treasury;
+
+        emit!(ProtocolFeeUpdatedEvent {
+            fee_bps,
+            treasury,
+            effective_at: config.pending_fee_effective_at,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when the protocol fee has been updated.
+pub struct ProtocolFeeUpdatedEvent {
+    /// The protocol fee taken from claimed rewards, in basis points.
+    pub fee_bps: u16,
+
+    /// The wallet whose token accounts receive the protocol fee.
+    pub treasury: Pubkey,
+
+    /// The time when the protocol fee takes effect.
+    pub effective_at: i64,
+}
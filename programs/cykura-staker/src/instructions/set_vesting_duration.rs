@@ -0,0 +1,43 @@
+use crate::*;
+
+/// Accounts for [cykura_staker::set_vesting_duration].
+#[derive(Accounts)]
+pub struct SetVestingDuration<'info> {
+    /// [Incentive] whose rewards vest.
+    #[account(mut, has_one = authority)]
+    pub incentive: Account<'info, Incentive>,
+
+    /// The authority of the incentive.
+    pub authority: Signer<'info>,
+}
+
+impl<'info> SetVestingDuration<'info> {
+    /// Sets the duration over which the rewards of an [Incentive] vest once credited.
+    ///
+    /// # Arguments
+    ///
+    /// * `vesting_duration` - The vesting duration in seconds, zero to make rewards claimable
+    /// immediately.
+    ///
+    pub fn set_vesting_duration(&mut self, vesting_duration: i64) -> Result<()> {
+        self.incentive.vesting_duration = vesting_duration;
+
+        emit!(VestingDurationUpdatedEvent {
+            incentive: self.incentive.key(),
+            vesting_duration,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when the vesting duration of an [Incentive] has been updated.
+pub struct VestingDurationUpdatedEvent {
+    /// The incentive whose rewards vest.
+    #[index]
+    pub incentive: Pubkey,
+
+    /// The vesting duration in seconds.
+    pub vesting_duration: i64,
+}
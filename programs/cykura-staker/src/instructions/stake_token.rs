@@ -28,9 +28,16 @@ pub struct StakeToken<'info> {
     pub stake: Account<'info, Stake>,
 
     /// The [Incentive] for which to stake the NFT.
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = incentive.boost_locker.is_none()
+    )]
     pub incentive: Account<'info, Incentive>,
 
+    /// The program [Config].
+    #[account(seeds = [b"Config".as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
     /// [Deposit] to be staked.
     #[account(
         mut,
@@ -119,12 +126,16 @@ impl<'info> StakeToken<'info> {
         stake.mint = self.deposit.mint;
         stake.incentive = self.incentive.key();
         stake.seconds_per_liquidity_inside_initial_x32 = seconds_per_liquidity_inside_x32;
-        stake.liquidity = self.pool.load()?.liquidity;
+        stake.snapshot_time = Clock::get().unwrap().unix_timestamp;
+        stake.staked_at = stake.snapshot_time;
+        stake.liquidity = self.tokenized_position.load()?.liquidity;
+        stake.effective_liquidity = stake.liquidity;
 
         emit!(StakeTokenEvent {
             mint: self.deposit.mint,
             incentive: self.incentive.key(),
             liquidity: stake.liquidity,
+            effective_liquidity: stake.effective_liquidity,
         });
 
         Ok(())
@@ -144,4 +155,7 @@ pub struct StakeTokenEvent {
 
     /// The amount of liquidity staked.
     pub liquidity: u64,
+
+    /// The staked liquidity after applying the boost.
+    pub effective_liquidity: u64,
 }
@@ -0,0 +1,107 @@
+use crate::ErrorCode;
+use crate::*;
+use cyclos_core::states::oracle::{ObservationState, OBSERVATION_SEED};
+use cyclos_core::states::pool::SnapshotCumulative;
+use cyclos_core::states::tick::TickState;
+use cyclos_core::states::tick::TICK_SEED;
+use cyclos_core::states::tokenized_position::TokenizedPositionState;
+use std::ops::Deref;
+
+/// Accounts for [cykura_staker::stake_token_multi].
+#[derive(Accounts)]
+pub struct StakeTokenMulti<'info> {
+    /// The program [Config].
+    #[account(seeds = [b"Config".as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// [Deposit] to be staked.
+    #[account(
+        mut,
+        has_one = owner @ErrorCode::OnlyOwnerCanStakeToken
+    )]
+    pub deposit: Account<'info, Deposit>,
+
+    /// The account having metadata of the Cykura Position NFT.
+    #[account(
+        constraint = tokenized_position.load()?.mint == deposit.mint,
+        constraint = tokenized_position.load()?.liquidity > 0 @ErrorCode::CannotStakeTokenWithZeroLiquidity,
+    )]
+    pub tokenized_position: AccountLoader<'info, TokenizedPositionState>,
+
+    /// The liquidity pool to which the LP position belongs.
+    #[account(address = tokenized_position.load()?.pool_id)]
+    pub pool: AccountLoader<'info, PoolState>,
+
+    /// The lower tick account of the position.
+    #[account(
+        address = Pubkey::create_program_address(&[
+            TICK_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &deposit.tick_lower.to_be_bytes(),
+            &[tick_lower.load()?.bump]
+        ], &cyclos_core::ID).unwrap()
+    )]
+    pub tick_lower: AccountLoader<'info, TickState>,
+
+    /// The upper tick account of the position.
+    #[account(
+        address = Pubkey::create_program_address(&[
+            TICK_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &deposit.tick_upper.to_be_bytes(),
+            &[tick_upper.load()?.bump]
+        ], &cyclos_core::ID).unwrap()
+    )]
+    pub tick_upper: AccountLoader<'info, TickState>,
+
+    /// The latest oracle observation for the pool.
+    #[account(
+        address = Pubkey::create_program_address(&[
+            &OBSERVATION_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &pool.load()?.observation_index.to_be_bytes(),
+            &[latest_observation.load()?.bump]
+        ], &cyclos_core::ID).unwrap() @ErrorCode::NotLatestObservation,
+    )]
+    pub latest_observation: AccountLoader<'info, ObservationState>,
+
+    /// The owner of the deposit, who pays for the stake accounts.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StakeTokenMulti<'info> {
+    /// Stakes a Cykura LP token in every incentive passed in the remaining accounts, validating
+    /// the position and snapshotting its seconds per liquidity once
+    pub fn stake_token_multi(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let liquidity = self.tokenized_position.load()?.liquidity;
+
+        let SnapshotCumulative {
+            seconds_per_liquidity_inside_x32,
+            ..
+        } = self.pool.load()?.snapshot_cumulatives_inside(
+            self.tick_lower.load()?.deref(),
+            self.tick_upper.load()?.deref(),
+            self.latest_observation.load()?.deref(),
+        );
+
+        rewards::create_stakes(
+            &mut self.deposit,
+            self.pool.key(),
+            &self.owner.to_account_info(),
+            &self.system_program.to_account_info(),
+            liquidity,
+            seconds_per_liquidity_inside_x32,
+            remaining_accounts,
+        )
+    }
+}
@@ -0,0 +1,158 @@
+use crate::ErrorCode;
+use crate::*;
+use cyclos_core::states::oracle::{ObservationState, OBSERVATION_SEED};
+use cyclos_core::states::pool::SnapshotCumulative;
+use cyclos_core::states::tick::TickState;
+use cyclos_core::states::tick::TICK_SEED;
+use cyclos_core::states::tokenized_position::TokenizedPositionState;
+use std::ops::Deref;
+
+/// Accounts for [cykura_staker::sync_stake_liquidity].
+#[derive(Accounts)]
+pub struct SyncStakeLiquidity<'info> {
+    /// [Stake]
+    #[account(mut, has_one = incentive)]
+    pub stake: Account<'info, Stake>,
+
+    /// The incentive of the stake. Boosted stakes are synced by [cykura_staker::poke_boost].
+    #[account(
+        mut,
+        constraint = incentive.boost_locker.is_none()
+    )]
+    pub incentive: Account<'info, Incentive>,
+
+    /// The deposit account of the position NFT.
+    #[account(constraint = deposit.mint == stake.mint)]
+    pub deposit: Account<'info, Deposit>,
+
+    /// The account having metadata of the Cykura Position NFT.
+    #[account(constraint = tokenized_position.load()?.mint == deposit.mint)]
+    pub tokenized_position: AccountLoader<'info, TokenizedPositionState>,
+
+    ///  The account to track unclaimed rewards for the deposit owner.
+    #[account(
+        mut,
+        constraint = reward.incentive == incentive.key(),
+        constraint = reward.reward_token == incentive.rewards[0].mint,
+        constraint = reward.owner == deposit.owner
+    )]
+    pub reward: Account<'info, Reward>,
+
+    /// The liquidity pool to which the LP position belongs.
+    #[account(address = incentive.pool)]
+    pub pool: AccountLoader<'info, PoolState>,
+
+    /// The lower tick account of the position.
+    #[account(
+        address = Pubkey::create_program_address(&[
+            TICK_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &deposit.tick_lower.to_be_bytes(),
+            &[tick_lower.load()?.bump]
+        ], &cyclos_core::ID).unwrap()
+    )]
+    pub tick_lower: AccountLoader<'info, TickState>,
+
+    /// The upper tick account of the position.
+    #[account(
+        address = Pubkey::create_program_address(&[
+            TICK_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &deposit.tick_upper.to_be_bytes(),
+            &[tick_upper.load()?.bump]
+        ], &cyclos_core::ID).unwrap()
+    )]
+    pub tick_upper: AccountLoader<'info, TickState>,
+
+    /// The latest oracle observation for the pool.
+    #[account(
+        address = Pubkey::create_program_address(&[
+            &OBSERVATION_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &pool.load()?.observation_index.to_be_bytes(),
+            &[latest_observation.load()?.bump]
+        ], &cyclos_core::ID).unwrap() @ErrorCode::NotLatestObservation,
+    )]
+    pub latest_observation: AccountLoader<'info, ObservationState>,
+}
+
+impl<'info> SyncStakeLiquidity<'info> {
+    /// Settles the rewards of a stake at its recorded liquidity, then snapshots the current
+    /// liquidity of the position
+    pub fn sync_stake_liquidity(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let block_timestamp = Clock::get().unwrap().unix_timestamp;
+        let incentive = &mut self.incentive;
+        let stake = &mut self.stake;
+
+        let SnapshotCumulative {
+            seconds_per_liquidity_inside_x32,
+            ..
+        } = self.pool.load()?.snapshot_cumulatives_inside(
+            self.tick_lower.load()?.deref(),
+            self.tick_upper.load()?.deref(),
+            self.latest_observation.load()?.deref(),
+        );
+
+        let rewards = incentive.accrue_reward(
+            stake.effective_liquidity,
+            stake.seconds_per_liquidity_inside_initial_x32,
+            seconds_per_liquidity_inside_x32,
+            stake.snapshot_time,
+            block_timestamp,
+        );
+        let rewards = incentive.apply_min_stake_duration(stake, rewards, false, block_timestamp);
+        rewards::credit_rewards(
+            incentive,
+            &self.deposit.owner,
+            &rewards,
+            block_timestamp,
+            &mut self.reward,
+            remaining_accounts,
+        )?;
+
+        stake.seconds_per_liquidity_inside_initial_x32 = seconds_per_liquidity_inside_x32;
+        stake.snapshot_time = block_timestamp;
+        stake.liquidity = self.tokenized_position.load()?.liquidity;
+        stake.effective_liquidity = stake.liquidity;
+
+        emit!(SyncStakeLiquidityEvent {
+            mint: stake.mint,
+            incentive: incentive.key(),
+            reward: rewards[0],
+            rewards,
+            liquidity: stake.liquidity,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when the liquidity of a stake has been synced with its position
+pub struct SyncStakeLiquidityEvent {
+    /// The unique identifier of a Cykura LP token.
+    #[index]
+    pub mint: Pubkey,
+
+    /// The incentive in which the token is staking.
+    #[index]
+    pub incentive: Pubkey,
+
+    /// The amount of the first reward token credited at the previous liquidity.
+    pub reward: u64,
+
+    /// The amount of every reward token credited at the previous liquidity.
+    pub rewards: [u64; MAX_REWARD_TOKENS],
+
+    /// The liquidity staked from now on.
+    pub liquidity: u64,
+}
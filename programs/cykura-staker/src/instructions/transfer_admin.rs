@@ -0,0 +1,41 @@
+use crate::*;
+
+/// Accounts for [cykura_staker::transfer_admin].
+#[derive(Accounts)]
+pub struct TransferAdmin<'info> {
+    /// [Config]
+    #[account(mut, has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    /// The admin of the program.
+    pub admin: Signer<'info>,
+}
+
+impl<'info> TransferAdmin<'info> {
+    /// Nominates a new admin, who becomes the admin once it accepts.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_admin` - The address nominated to become the admin.
+    ///
+    pub fn transfer_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        self.config.pending_admin = Some(new_admin);
+
+        emit!(AdminTransferStartedEvent {
+            admin: self.config.admin,
+            pending_admin: new_admin,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event emitted when a new admin has been nominated.
+pub struct AdminTransferStartedEvent {
+    /// The current admin.
+    pub admin: Pubkey,
+
+    /// The address nominated to become the admin.
+    pub pending_admin: Pubkey,
+}
@@ -0,0 +1,114 @@
+use crate::ErrorCode;
+use crate::*;
+use anchor_lang::AccountsClose;
+use cyclos_core::states::oracle::{ObservationState, OBSERVATION_SEED};
+use cyclos_core::states::pool::SnapshotCumulative;
+use cyclos_core::states::tick::TickState;
+use cyclos_core::states::tick::TICK_SEED;
+use rewards::StakeAccounts;
+use std::ops::Deref;
+
+/// Accounts for [cykura_staker::unstake_all].
+#[derive(Accounts)]
+pub struct UnstakeAll<'info> {
+    /// [Deposit] to unstake from every incentive.
+    #[account(mut, has_one = owner @ErrorCode::OnlyOwnerCanWithdrawToken)]
+    pub deposit: Account<'info, Deposit>,
+
+    /// The owner of the deposit, who receives the rent of the closed stakes.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The liquidity pool to which the LP position belongs.
+    pub pool: AccountLoader<'info, PoolState>,
+
+    /// The lower tick account of the position.
+    #[account(
+        address = Pubkey::create_program_address(&[
+            TICK_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &deposit.tick_lower.to_be_bytes(),
+            &[tick_lower.load()?.bump]
+        ], &cyclos_core::ID).unwrap()
+    )]
+    pub tick_lower: AccountLoader<'info, TickState>,
+
+    /// The upper tick account of the position.
+    #[account(
+        address = Pubkey::create_program_address(&[
+            TICK_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &deposit.tick_upper.to_be_bytes(),
+            &[tick_upper.load()?.bump]
+        ], &cyclos_core::ID).unwrap()
+    )]
+    pub tick_upper: AccountLoader<'info, TickState>,
+
+    /// The latest oracle observation for the pool.
+    #[account(
+        address = Pubkey::create_program_address(&[
+            &OBSERVATION_SEED.as_bytes(),
+            pool.load()?.token_0.as_ref(),
+            pool.load()?.token_1.as_ref(),
+            &pool.load()?.fee.to_be_bytes(),
+            &pool.load()?.observation_index.to_be_bytes(),
+            &[latest_observation.load()?.bump]
+        ], &cyclos_core::ID).unwrap() @ErrorCode::NotLatestObservation,
+    )]
+    pub latest_observation: AccountLoader<'info, ObservationState>,
+}
+
+impl<'info> UnstakeAll<'info> {
+    /// Unstakes a Cykura LP token from every incentive, crediting the accrued rewards. Returns the
+    /// remaining accounts following the stakes.
+    pub fn unstake_all<'a>(
+        &mut self,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<&'a [AccountInfo<'info>]> {
+        let block_timestamp = Clock::get().unwrap().unix_timestamp;
+        let deposit = &mut self.deposit;
+
+        let SnapshotCumulative {
+            seconds_per_liquidity_inside_x32,
+            ..
+        } = self.pool.load()?.snapshot_cumulatives_inside(
+            self.tick_lower.load()?.deref(),
+            self.tick_upper.load()?.deref(),
+            self.latest_observation.load()?.deref(),
+        );
+
+        let mut accounts = remaining_accounts;
+        let mut incentives = Vec::with_capacity(deposit.number_of_stakes as usize);
+        while deposit.number_of_stakes > 0 {
+            let mut stake_accounts = StakeAccounts::next(deposit, &mut accounts, &mut incentives)?;
+            require!(
+                stake_accounts.incentive.pool == self.pool.key(),
+                ErrorCode::TokenPoolIsNotTheIncentivePool
+            );
+
+            deposit.number_of_stakes -= 1;
+            let incentive = &mut stake_accounts.incentive;
+            incentive.number_of_stakes -= 1;
+            incentive.total_staked_voting_power -= stake_accounts.stake.voting_power;
+
+            stake_accounts.settle(
+                &deposit.owner,
+                seconds_per_liquidity_inside_x32,
+                true,
+                block_timestamp,
+            )?;
+            stake_accounts.stake.close(self.owner.to_account_info())?;
+
+            emit!(UnstakeTokenEvent {
+                mint: deposit.mint,
+                incentive: stake_accounts.incentive.key()
+            });
+        }
+
+        Ok(accounts)
+    }
+}
@@ -1,4 +1,3 @@
-use crate::reward_math::RewardOwed;
 use crate::ErrorCode;
 use crate::*;
 use anchor_lang::AccountsClose;
@@ -16,10 +15,7 @@ pub struct UnstakeToken<'info> {
     pub stake: Account<'info, Stake>,
 
     /// The incentive for which to unstake the position NFT.
-    #[account(
-        mut,
-        constraint = incentive.boost_locker.is_none()
-    )]
+    #[account(mut)]
     pub incentive: Account<'info, Incentive>,
 
     /// The deposit account of the position NFT.
@@ -29,7 +25,8 @@ pub struct UnstakeToken<'info> {
     ///  The account to track unclaimed rewards for the deposit owner.
     #[account(
         mut,
-        constraint = reward.reward_token == incentive.reward_token,
+        constraint = reward.incentive == incentive.key(),
+        constraint = reward.reward_token == incentive.rewards[0].mint,
         constraint = reward.owner == deposit.owner
     )]
     pub reward: Account<'info, Reward>,
@@ -83,14 +80,11 @@ pub struct UnstakeToken<'info> {
 
 impl<'info> UnstakeToken<'info> {
     /// Unstakes a Cykura LP token
-    pub fn unstake_token(&mut self, block_timestamp: i64) -> Result<()> {
-        let deposit = &mut self.deposit;
-        let incentive = &mut self.incentive;
-        let stake = &mut self.stake;
-
-        deposit.number_of_stakes -= 1;
-        incentive.number_of_stakes -= 1;
-
+    pub fn unstake_token(
+        &mut self,
+        block_timestamp: i64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
         let SnapshotCumulative {
             seconds_per_liquidity_inside_x32,
             ..
@@ -100,36 +94,72 @@ impl<'info> UnstakeToken<'info> {
             self.latest_observation.load()?.deref(),
         );
 
-        let RewardOwed {
-            reward,
-            seconds_inside_x32,
-        } = reward_math::compute_reward_amount(
-            incentive.total_reward_unclaimed,
-            incentive.total_seconds_claimed_x32,
-            incentive.start_time,
-            incentive.end_time,
-            stake.liquidity,
-            stake.seconds_per_liquidity_inside_initial_x32,
+        unstake(
+            &mut self.stake,
+            &mut self.incentive,
+            &mut self.deposit,
+            &mut self.reward,
             seconds_per_liquidity_inside_x32,
             block_timestamp,
-        );
+            remaining_accounts,
+        )?;
 
-        incentive.total_seconds_claimed_x32 += seconds_inside_x32;
-        // reward is never greater than total reward unclaimed
-        incentive.total_reward_unclaimed -= reward;
-        self.reward.rewards_owed += reward;
-
-        stake.close(self.signer.to_account_info())?;
-
-        emit!(UnstakeTokenEvent {
-            mint: deposit.mint,
-            incentive: incentive.key()
-        });
-
-        Ok(())
+        self.stake.close(self.signer.to_account_info())
     }
 }
 
+/// Removes a [Stake] from its incentive and credits the rewards accrued since its last snapshot
+/// to the deposit owner. The caller closes the stake. Shared by [cykura_staker::unstake_token]
+/// and [cykura_staker::unstake_token_boosted].
+///
+/// # Arguments
+///
+/// * `stake` - The stake to remove
+/// * `incentive` - The incentive of the stake
+/// * `deposit` - The staked deposit
+/// * `reward` - The [Reward] account of the deposit owner for the first reward token
+/// * `seconds_per_liquidity_inside_x32` - The seconds per liquidity of the position range as of now
+/// * `block_timestamp` - The current block timestamp
+/// * `remaining_accounts` - The [Reward] accounts of the other reward tokens
+///
+pub fn unstake<'info>(
+    stake: &mut Account<'info, Stake>,
+    incentive: &mut Account<'info, Incentive>,
+    deposit: &mut Account<'info, Deposit>,
+    reward: &mut Account<'info, Reward>,
+    seconds_per_liquidity_inside_x32: u64,
+    block_timestamp: i64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    deposit.number_of_stakes -= 1;
+    incentive.number_of_stakes -= 1;
+    incentive.total_staked_voting_power -= stake.voting_power;
+
+    let rewards = incentive.accrue_reward(
+        stake.effective_liquidity,
+        stake.seconds_per_liquidity_inside_initial_x32,
+        seconds_per_liquidity_inside_x32,
+        stake.snapshot_time,
+        block_timestamp,
+    );
+    let rewards = incentive.apply_min_stake_duration(stake, rewards, true, block_timestamp);
+    rewards::credit_rewards(
+        incentive,
+        &deposit.owner,
+        &rewards,
+        block_timestamp,
+        reward,
+        remaining_accounts,
+    )?;
+
+    emit!(UnstakeTokenEvent {
+        mint: deposit.mint,
+        incentive: incentive.key()
+    });
+
+    Ok(())
+}
+
 #[event]
 /// Event emitted when a Cykura LP token has been unstaked
 pub struct UnstakeTokenEvent {
@@ -1,4 +1,3 @@
-use crate::reward_math::RewardOwed;
 use crate::ErrorCode;
 use crate::*;
 use anchor_lang::AccountsClose;
@@ -6,7 +5,7 @@ use cyclos_core::states::oracle::{ObservationState, OBSERVATION_SEED};
 use cyclos_core::states::pool::SnapshotCumulative;
 use cyclos_core::states::tick::TickState;
 use cyclos_core::states::tick::TICK_SEED;
-use locked_voter::{Escrow, Locker};
+use locked_voter::Locker;
 use std::ops::Deref;
 
 /// Accounts for [cykura_staker::unstake_token_boosted].
@@ -21,15 +20,13 @@ pub struct UnstakeTokenBoosted<'info> {
     pub incentive: Account<'info, Incentive>,
 
     /// The boost locker.
-    #[account(address = incentive.boost_locker.unwrap())]
+    #[account(constraint = incentive.boost_locker == Some(locker.key()) @ErrorCode::InvalidLocker)]
     pub locker: Account<'info, Locker>,
 
-    /// The staker's vote locker escrow.
-    #[account(
-        constraint = escrow.locker == locker.key(),
-        constraint = escrow.owner == signer.key(),
-    )]
-    pub escrow: Account<'info, Escrow>,
+    /// The deposit owner's vote locker escrow.
+    /// CHECK: Unused since the boost is snapshotted at stake time. Kept so that existing clients
+    /// can still unstake boosted stakes.
+    pub escrow: UncheckedAccount<'info>,
 
     /// The deposit account of the position NFT.
     #[account(mut, constraint = deposit.mint == stake.mint)]
@@ -38,7 +35,8 @@ pub struct UnstakeTokenBoosted<'info> {
     ///  The account to track unclaimed rewards for the deposit owner.
     #[account(
         mut,
-        constraint = reward.reward_token == incentive.reward_token,
+        constraint = reward.incentive == incentive.key(),
+        constraint = reward.reward_token == incentive.rewards[0].mint,
         constraint = reward.owner == deposit.owner
     )]
     pub reward: Account<'info, Reward>,
@@ -86,23 +84,17 @@ pub struct UnstakeTokenBoosted<'info> {
     )]
     pub latest_observation: AccountLoader<'info, ObservationState>,
 
-    /// The deposit owner.
-    #[account(address = deposit.owner @ErrorCode::OnlyOwnerCanUnstakeFromBoostedIncentive)]
+    /// The instruction signer. The must be the owner of the deposit, if end time has not passed.
     pub signer: Signer<'info>,
 }
 
 impl<'info> UnstakeTokenBoosted<'info> {
-    /// Unstakes a Cykura LP token, with rewards boosted by voting power
-    pub fn unstake_token_boosted(&mut self) -> Result<()> {
-        let block_timestamp = Clock::get().unwrap().unix_timestamp;
-        let deposit = &mut self.deposit;
-        let incentive = &mut self.incentive;
-        let stake = &mut self.stake;
-        let locker = &self.locker;
-
-        deposit.number_of_stakes -= 1;
-        incentive.number_of_stakes -= 1;
-
+    /// Unstakes a Cykura LP token from a boosted incentive, see [unstake_token::unstake]
+    pub fn unstake_token_boosted(
+        &mut self,
+        block_timestamp: i64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
         let SnapshotCumulative {
             seconds_per_liquidity_inside_x32,
             ..
@@ -112,47 +104,16 @@ impl<'info> UnstakeTokenBoosted<'info> {
             self.latest_observation.load()?.deref(),
         );
 
-        let voting_power = locker
-            .params
-            .calculate_voter_power(&self.escrow, block_timestamp)
-            .unwrap();
-
-        // The voting power when entire supply is locked for the max period.
-        // This is a hypothetical ceiling, not the total locked power at a point of time.
-        let max_voting_power = locker
-            .locked_supply
-            .checked_mul(locker.params.max_stake_vote_multiplier.into())
-            .unwrap();
-
-        let RewardOwed {
-            reward,
-            seconds_inside_x32,
-        } = reward_math::compute_reward_amount_boosted(
-            incentive.total_reward_unclaimed,
-            incentive.total_seconds_claimed_x32,
-            incentive.start_time,
-            incentive.end_time,
-            stake.liquidity,
-            stake.seconds_per_liquidity_inside_initial_x32,
+        unstake_token::unstake(
+            &mut self.stake,
+            &mut self.incentive,
+            &mut self.deposit,
+            &mut self.reward,
             seconds_per_liquidity_inside_x32,
             block_timestamp,
-            self.pool.load()?.liquidity,
-            voting_power,
-            max_voting_power,
-        );
-
-        incentive.total_seconds_claimed_x32 += seconds_inside_x32;
-        // reward is never greater than total reward unclaimed
-        incentive.total_reward_unclaimed -= reward;
-        self.reward.rewards_owed += reward;
-
-        stake.close(self.signer.to_account_info())?;
-
-        emit!(UnstakeTokenEvent {
-            mint: deposit.mint,
-            incentive: incentive.key()
-        });
+            remaining_accounts,
+        )?;
 
-        Ok(())
+        self.stake.close(self.signer.to_account_info())
     }
 }
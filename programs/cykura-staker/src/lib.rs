@@ -2,8 +2,10 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::*;
 use cyclos_core::states::pool::PoolState;
 
+mod boost;
 mod instructions;
 mod reward_math;
+mod rewards;
 mod state;
 
 pub use instructions::*;
@@ -11,100 +13,387 @@ pub use state::*;
 
 declare_id!("LiquB13Cv6ZJsCYaPHY9Gxt1YN46gZx9nLAscgM7YR1");
 
-// Constructor constants, read from Etherscan
+// Constructor constants, read from Etherscan. Used as the defaults of [Config].
 /// The max duration of an incentive in seconds
 const MAX_INCENTIVE_DURATION: i64 = 63072000;
 /// The max amount of seconds into the future the incentive start_time can be set
 const MAX_INCENTIVE_START_LEAD_TIME: i64 = 2592000;
+/// The max protocol fee on claimed rewards, in basis points
+const MAX_PROTOCOL_FEE_BPS: u16 = 1000;
+/// The delay before an increase of the protocol fee takes effect, in seconds
+const PROTOCOL_FEE_TIMELOCK: i64 = 604800;
+/// The max bonus on rewards claimed into a lock, in basis points
+const MAX_LOCK_BONUS_BPS: u16 = 1000;
+/// The min duration from the claim for which a lock must last to earn the lock bonus, in seconds
+const MIN_LOCK_BONUS_DURATION: i64 = 7776000;
+/// The max duration over which credited rewards vest, in seconds
+const MAX_VESTING_DURATION: i64 = 31536000;
+
+// Upper bounds of the [Config] limits. Longer incentives can overflow the x32 liquidity-seconds
+// of the reward math.
+/// The upper bound of the max incentive duration, in seconds
+const MAX_INCENTIVE_DURATION_CAP: i64 = 157680000;
+/// The upper bound of the max incentive start lead time, in seconds
+const MAX_INCENTIVE_START_LEAD_TIME_CAP: i64 = 31536000;
+/// How long rewards stay claimable after the latest possible end of an incentive, in seconds
+const REWARD_CLAIM_PERIOD: i64 = 31536000;
 
 #[program]
 pub mod cykura_staker {
     use super::*;
 
-    /// Creates a new liquidity mining [Incentive]
-    pub fn create_incentive(
-        ctx: Context<CreateIncentive>,
-        start_time: i64,
-        end_time: i64,
+    /// Initializes the program [Config]. Only the upgrade authority of the program can call this.
+    pub fn initialize_config(ctx: Context<InitializeConfig>, admin: Pubkey) -> Result<()> {
+        ctx.accounts
+            .initialize_config(*ctx.bumps.get("config").unwrap(), admin)
+    }
+
+    /// Updates the limits of the program [Config]. The max incentive duration is capped at five
+    /// years and the max start lead time at one year.
+    pub fn set_config(
+        ctx: Context<SetConfig>,
+        max_incentive_duration: i64,
+        max_incentive_start_lead_time: i64,
+        incentive_creator: Option<Pubkey>,
     ) -> Result<()> {
-        let block_timestamp = Clock::get().unwrap().unix_timestamp;
-        require!(
-            block_timestamp < start_time,
-            ErrorCode::StartTimeMustBeNowOrInTheFuture
-        );
-        require!(
-            start_time - block_timestamp <= MAX_INCENTIVE_START_LEAD_TIME,
-            ErrorCode::StartTimeTooFarIntoFuture
-        );
         require!(
-            start_time < end_time,
-            ErrorCode::StartTimeMustBeBeforeEndTime
+            (1..=MAX_INCENTIVE_DURATION_CAP).contains(&max_incentive_duration)
+                && (1..=MAX_INCENTIVE_START_LEAD_TIME_CAP).contains(&max_incentive_start_lead_time),
+            ErrorCode::InvalidConfig
         );
+
+        ctx.accounts.set_config(
+            max_incentive_duration,
+            max_incentive_start_lead_time,
+            incentive_creator,
+        )
+    }
+
+    /// Sets the protocol fee taken from claimed rewards, and the treasury receiving it.
+    /// Fee increases take effect after a one week timelock
+    pub fn set_protocol_fee(
+        ctx: Context<SetProtocolFee>,
+        fee_bps: u16,
+        treasury: Pubkey,
+    ) -> Result<()> {
         require!(
-            end_time - start_time < MAX_INCENTIVE_DURATION,
-            ErrorCode::IncentiveDurationIsTooLong
+            fee_bps <= MAX_PROTOCOL_FEE_BPS,
+            ErrorCode::ProtocolFeeTooLarge
         );
 
         ctx.accounts
-            .create_incentive(*ctx.bumps.get("incentive").unwrap(), start_time, end_time)
+            .set_protocol_fee(fee_bps, treasury, Clock::get().unwrap().unix_timestamp)
+    }
+
+    /// Enables or disables the pool and reward token allowlists for new incentives
+    pub fn set_allowlist_enabled(ctx: Context<SetAllowlistEnabled>, enabled: bool) -> Result<()> {
+        ctx.accounts.set_allowlist_enabled(enabled)
+    }
+
+    /// Allows a pool for incentives
+    pub fn allow_pool(ctx: Context<AllowPool>) -> Result<()> {
+        ctx.accounts
+            .allow_pool(*ctx.bumps.get("allowed_pool").unwrap())
+    }
+
+    /// Removes a pool from the allowlist
+    pub fn disallow_pool(ctx: Context<DisallowPool>) -> Result<()> {
+        ctx.accounts.disallow_pool()
+    }
+
+    /// Allows a token for incentive rewards
+    pub fn allow_mint(ctx: Context<AllowMint>) -> Result<()> {
+        ctx.accounts
+            .allow_mint(*ctx.bumps.get("allowed_mint").unwrap())
+    }
+
+    /// Removes a token from the allowlist
+    pub fn disallow_mint(ctx: Context<DisallowMint>) -> Result<()> {
+        ctx.accounts.disallow_mint()
+    }
+
+    /// Nominates a new admin of the program [Config]
+    pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.transfer_admin(new_admin)
+    }
+
+    /// Accepts the admin role of the program [Config]
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        ctx.accounts.accept_admin()
+    }
+
+    /// Pauses or unpauses staking, adding rewards and claiming rewards for every [Incentive]
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        ctx.accounts.set_paused(paused)
+    }
+
+    /// Creates a new liquidity mining [Incentive]
+    ///
+    /// Rewards are emitted uniformly over the incentive. The `emission_schedule` must keep a
+    /// constant rate until stake accrual is checkpointed at every emission point.
+    ///
+    /// While the allowlists are enabled, the pool and reward token must be allowed unless the
+    /// incentive is created as `permissionless`.
+    pub fn create_incentive(
+        ctx: Context<CreateIncentive>,
+        start_time: i64,
+        end_time: i64,
+        emission_schedule: Vec<EmissionPoint>,
+        permissionless: bool,
+    ) -> Result<()> {
+        ctx.accounts.config.validate_incentive_times(
+            start_time,
+            end_time,
+            Clock::get().unwrap().unix_timestamp,
+        )?;
+        let permissionless = ctx.accounts.config.validate_allowlist(
+            permissionless,
+            &ctx.accounts.allowed_pool,
+            &ctx.accounts.allowed_reward_token,
+        )?;
+
+        ctx.accounts.create_incentive(
+            *ctx.bumps.get("incentive").unwrap(),
+            start_time,
+            end_time,
+            &emission_schedule,
+            permissionless,
+        )
     }
 
     /// Creates a new [Incentive], boosted by voting power in the provided [Locker].
+    ///
+    /// `boost_base_percent` of staked liquidity earns rewards regardless of voting power. The rest
+    /// is earned in proportion to the staker's share of the `boost_denominator` voting power.
     pub fn create_incentive_boosted(
         ctx: Context<CreateIncentiveBoosted>,
         start_time: i64,
         end_time: i64,
+        boost_base_percent: u8,
+        boost_denominator: BoostDenominator,
+        emission_schedule: Vec<EmissionPoint>,
+        permissionless: bool,
     ) -> Result<()> {
-        let block_timestamp = Clock::get().unwrap().unix_timestamp;
+        ctx.accounts.config.validate_incentive_times(
+            start_time,
+            end_time,
+            Clock::get().unwrap().unix_timestamp,
+        )?;
+        let permissionless = ctx.accounts.config.validate_allowlist(
+            permissionless,
+            &ctx.accounts.allowed_pool,
+            &ctx.accounts.allowed_reward_token,
+        )?;
         require!(
-            block_timestamp < start_time,
-            ErrorCode::StartTimeMustBeNowOrInTheFuture
+            boost_base_percent <= 100,
+            ErrorCode::BoostBasePercentTooLarge
         );
+
+        ctx.accounts.create_incentive_boosted(
+            *ctx.bumps.get("incentive").unwrap(),
+            start_time,
+            end_time,
+            boost_base_percent,
+            boost_denominator,
+            &emission_schedule,
+            permissionless,
+        )
+    }
+
+    /// Adds another reward token to an [Incentive], before the incentive starts
+    pub fn add_reward_token(ctx: Context<AddRewardToken>) -> Result<()> {
+        let incentive = &ctx.accounts.incentive;
         require!(
-            start_time - block_timestamp <= MAX_INCENTIVE_START_LEAD_TIME,
-            ErrorCode::StartTimeTooFarIntoFuture
+            Clock::get().unwrap().unix_timestamp < incentive.start_time,
+            ErrorCode::CannotAddRewardTokenAfterStartTime
         );
         require!(
-            start_time < end_time,
-            ErrorCode::StartTimeMustBeBeforeEndTime
+            incentive
+                .reward_index(&ctx.accounts.reward_token.key())
+                .is_none(),
+            ErrorCode::DuplicateRewardToken
         );
         require!(
-            end_time - start_time < MAX_INCENTIVE_DURATION,
-            ErrorCode::IncentiveDurationIsTooLong
+            incentive.reward_token_count() < MAX_REWARD_TOKENS,
+            ErrorCode::TooManyRewardTokens
         );
 
-        ctx.accounts.create_incentive_boosted(
-            *ctx.bumps.get("incentive").unwrap(),
-            start_time,
-            end_time,
-        )
+        if !incentive.permissionless {
+            require!(
+                Account::<AllowedMint>::try_from(&ctx.accounts.allowed_reward_token).is_ok(),
+                ErrorCode::RewardTokenNotAllowed
+            );
+        }
+
+        ctx.accounts.add_reward_token()
     }
 
     /// Adds a reward to an [Incentive]
     pub fn add_reward(ctx: Context<AddReward>, reward: u64) -> Result<()> {
         require!(reward > 0, ErrorCode::RewardMustBePositive);
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        require!(!ctx.accounts.incentive.paused, ErrorCode::IncentivePaused);
 
         ctx.accounts.add_reward(reward)
     }
 
-    /// Ends an [Incentive] after the incentive end time has passed and all stakes have been withdrawn
-    pub fn end_incentive(ctx: Context<EndIncentive>) -> Result<()> {
+    /// Extends the end time of an [Incentive] and adds rewards to it, before the incentive ends.
+    /// Existing stakes keep accruing rewards over the extended period. `emission_points` are
+    /// appended to the emission schedule, and the last emission rate carries on if empty.
+    pub fn extend_incentive(
+        ctx: Context<ExtendIncentive>,
+        end_time: i64,
+        reward: u64,
+        emission_points: Vec<EmissionPoint>,
+    ) -> Result<()> {
+        let current_time = Clock::get().unwrap().unix_timestamp;
         let incentive = &ctx.accounts.incentive;
         require!(
-            Clock::get().unwrap().unix_timestamp > incentive.end_time,
-            ErrorCode::CannotEndIncentiveBeforeEndTime
+            current_time < incentive.end_time,
+            ErrorCode::CannotExtendIncentiveAfterEndTime
         );
         require!(
-            incentive.total_reward_unclaimed > 0,
-            ErrorCode::NoRefundAvailable
+            end_time > incentive.end_time,
+            ErrorCode::EndTimeMustBeAfterCurrentEndTime
+        );
+        require!(
+            end_time - incentive.start_time < ctx.accounts.config.max_incentive_duration,
+            ErrorCode::IncentiveDurationIsTooLong
+        );
+        require!(reward > 0, ErrorCode::RewardMustBePositive);
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        require!(!incentive.paused, ErrorCode::IncentivePaused);
+
+        ctx.accounts
+            .extend_incentive(end_time, reward, &emission_points, current_time)
+    }
+
+    /// Ends an [Incentive] after the incentive end time has passed and all stakes have been withdrawn.
+    /// Unclaimed rewards are refunded and the incentive account is closed.
+    pub fn end_incentive<'info>(
+        ctx: Context<'_, '_, '_, 'info, EndIncentive<'info>>,
+    ) -> Result<()> {
+        let incentive = &ctx.accounts.incentive;
+        require!(
+            Clock::get().unwrap().unix_timestamp > incentive.end_time,
+            ErrorCode::CannotEndIncentiveBeforeEndTime
         );
         require!(
             incentive.number_of_stakes == 0,
             ErrorCode::CannotEndIncentiveWhileDepositsAreStaked
         );
 
+        ctx.accounts.end_incentive(
+            *ctx.bumps.get("stake_manager").unwrap(),
+            ctx.remaining_accounts,
+        )
+    }
+
+    /// Closes a reward vault of an ended [Incentive], sending its rent to the treasury.
+    /// Vaults still holding rewards owed to users are left open by `end_incentive`. They can be
+    /// closed once empty, or once the claim period after the latest possible end of the incentive
+    /// has passed, sweeping the tokens left to the refundee.
+    ///
+    /// `start_time` and `end_time` are the times the incentive was created with.
+    pub fn close_vault(ctx: Context<CloseVault>, start_time: i64, end_time: i64) -> Result<()> {
+        // extensions can push the end time out to the max incentive duration
+        let latest_end_time = end_time.max(start_time + MAX_INCENTIVE_DURATION_CAP);
+        require!(
+            ctx.accounts.vault.amount == 0
+                || Clock::get().unwrap().unix_timestamp >= latest_end_time + REWARD_CLAIM_PERIOD,
+            ErrorCode::VaultNotEmpty
+        );
+
         ctx.accounts
-            .end_incentive(*ctx.bumps.get("stake_manager").unwrap())
+            .close_vault(*ctx.bumps.get("stake_manager").unwrap())
+    }
+
+    /// Pauses or unpauses staking and adding rewards for an [Incentive], without pausing claims
+    pub fn set_incentive_paused(ctx: Context<SetIncentivePaused>, paused: bool) -> Result<()> {
+        ctx.accounts.set_incentive_paused(paused)
+    }
+
+    /// Sets the bonus paid on rewards of a boosted [Incentive] claimed into a lock, before the
+    /// incentive starts
+    pub fn set_lock_bonus(ctx: Context<SetLockBonus>, lock_bonus_bps: u16) -> Result<()> {
+        require!(
+            Clock::get().unwrap().unix_timestamp < ctx.accounts.incentive.start_time,
+            ErrorCode::CannotSetLockBonusAfterStartTime
+        );
+        require!(
+            lock_bonus_bps <= MAX_LOCK_BONUS_BPS,
+            ErrorCode::LockBonusTooLarge
+        );
+
+        ctx.accounts.set_lock_bonus(lock_bonus_bps)
+    }
+
+    /// Adds tokens to the reserve paying the lock bonus of a boosted [Incentive]. The reserve is
+    /// refunded with the leftover rewards when the incentive ends.
+    pub fn add_lock_bonus(ctx: Context<AddLockBonus>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::RewardMustBePositive);
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        require!(!ctx.accounts.incentive.paused, ErrorCode::IncentivePaused);
+
+        ctx.accounts.add_lock_bonus(amount)
+    }
+
+    /// Sets the duration over which credited rewards of an [Incentive] vest, before the
+    /// incentive starts
+    pub fn set_vesting_duration(
+        ctx: Context<SetVestingDuration>,
+        vesting_duration: i64,
+    ) -> Result<()> {
+        require!(
+            Clock::get().unwrap().unix_timestamp < ctx.accounts.incentive.start_time,
+            ErrorCode::CannotSetVestingDurationAfterStartTime
+        );
+        require!(
+            (0..=MAX_VESTING_DURATION).contains(&vesting_duration),
+            ErrorCode::InvalidVestingDuration
+        );
+
+        ctx.accounts.set_vesting_duration(vesting_duration)
+    }
+
+    /// Sets the minimum stake duration of an [Incentive] and the share of rewards forfeited when
+    /// a stake is removed earlier, before the incentive starts
+    pub fn set_min_stake_duration(
+        ctx: Context<SetMinStakeDuration>,
+        min_stake_duration: i64,
+        early_unstake_penalty_bps: u16,
+    ) -> Result<()> {
+        let incentive = &ctx.accounts.incentive;
+        require!(
+            Clock::get().unwrap().unix_timestamp < incentive.start_time,
+            ErrorCode::CannotSetMinStakeDurationAfterStartTime
+        );
+        require!(
+            (0..=incentive.end_time - incentive.start_time).contains(&min_stake_duration)
+                && early_unstake_penalty_bps <= 10_000,
+            ErrorCode::InvalidMinStakeDuration
+        );
+
+        ctx.accounts
+            .set_min_stake_duration(min_stake_duration, early_unstake_penalty_bps)
+    }
+
+    /// Cancels an [Incentive] before it starts, or while nothing is staked in it.
+    /// Unclaimed rewards are refunded and the incentive account is closed.
+    pub fn cancel_incentive<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelIncentive<'info>>,
+    ) -> Result<()> {
+        let incentive = &ctx.accounts.end_incentive.incentive;
+        require!(
+            Clock::get().unwrap().unix_timestamp < incentive.start_time
+                || incentive.number_of_stakes == 0,
+            ErrorCode::CannotCancelIncentiveWhileDepositsAreStaked
+        );
+
+        ctx.accounts.cancel_incentive(
+            *ctx.bumps.get("stake_manager").unwrap(),
+            ctx.remaining_accounts,
+        )
     }
 
     /// Creates a new [Deposit] by staking a position NFT.
@@ -113,6 +402,19 @@ pub mod cykura_staker {
             .create_deposit(*ctx.bumps.get("deposit").unwrap())
     }
 
+    /// Deposits a Cykura position token and stakes it in one or more incentives.
+    ///
+    /// Every [Incentive] is passed with the [Stake] account to create in pairs as remaining
+    /// accounts.
+    pub fn deposit_and_stake<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositAndStake<'info>>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        ctx.accounts
+            .deposit_and_stake(*ctx.bumps.get("deposit").unwrap(), ctx.remaining_accounts)
+    }
+
     /// Transfers ownership of a deposit to the given recipient.
     pub fn transfer_deposit(ctx: Context<TransferDeposit>) -> Result<()> {
         ctx.accounts.transfer_deposit()
@@ -128,32 +430,120 @@ pub mod cykura_staker {
 
     /// Stakes a Cykura LP token
     pub fn stake_token(ctx: Context<StakeToken>) -> Result<()> {
-        let incentive = &ctx.accounts.incentive;
-        let block_timestamp = Clock::get().unwrap().unix_timestamp;
-        require!(
-            block_timestamp >= incentive.start_time,
-            ErrorCode::IncentiveNotStarted
-        );
-        require!(
-            block_timestamp < incentive.end_time,
-            ErrorCode::IncentiveEnded
-        );
-        require!(
-            incentive.total_reward_unclaimed > 0,
-            ErrorCode::NonExistentIncentive
-        );
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        ctx.accounts
+            .incentive
+            .validate_stake(Clock::get().unwrap().unix_timestamp)?;
 
         ctx.accounts.stake_token(*ctx.bumps.get("stake").unwrap())
     }
 
-    /// Creates an empty [Reward] account for a given token and address.
+    /// Stakes a Cykura LP token in a boosted [Incentive]. The boost from the owner's voting
+    /// power is snapshotted at stake time.
+    pub fn stake_token_boosted(ctx: Context<StakeTokenBoosted>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        ctx.accounts
+            .incentive
+            .validate_stake(Clock::get().unwrap().unix_timestamp)?;
+
+        ctx.accounts
+            .stake_token_boosted(*ctx.bumps.get("stake").unwrap())
+    }
+
+    /// Stakes a Cykura LP token in several unboosted incentives of its pool at once.
+    ///
+    /// Every [Incentive] is passed as remaining accounts, followed by the address of its [Stake].
+    pub fn stake_token_multi<'info>(
+        ctx: Context<'_, '_, '_, 'info, StakeTokenMulti<'info>>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        ctx.accounts.stake_token_multi(ctx.remaining_accounts)
+    }
+
+    /// Settles the rewards of a boosted stake and recomputes its boost from the owner's
+    /// current voting power. Anyone can poke a stake.
+    pub fn poke_boost<'info>(ctx: Context<'_, '_, '_, 'info, PokeBoost<'info>>) -> Result<()> {
+        ctx.accounts.poke_boost(ctx.remaining_accounts)
+    }
+
+    /// Unstakes a deposit from every [Incentive] it is staked in, forfeiting the rewards accrued
+    /// since the last settlement. The NFT can then be withdrawn with `withdraw_token`.
+    ///
+    /// Every [Stake] of the deposit is passed with its [Incentive] in pairs as remaining accounts.
+    pub fn emergency_withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, EmergencyWithdraw<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.emergency_withdraw(ctx.remaining_accounts)
+    }
+
+    /// Collects the swap fees of a deposited position to the owner, while the deposit stays staked.
+    pub fn collect_fees(
+        ctx: Context<CollectFees>,
+        amount_0_max: u64,
+        amount_1_max: u64,
+    ) -> Result<()> {
+        ctx.accounts.collect_fees(
+            *ctx.bumps.get("stake_manager").unwrap(),
+            amount_0_max,
+            amount_1_max,
+        )
+    }
+
+    /// Adds liquidity to a deposited position, while the deposit stays staked.
+    ///
+    /// Every [Stake] of the deposit is passed as remaining accounts, followed by its [Incentive]
+    /// and the [Reward] accounts of the owner for every reward token of the incentive.
+    pub fn increase_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, IncreaseLiquidity<'info>>,
+        amount_0_desired: u64,
+        amount_1_desired: u64,
+        amount_0_minimum: u64,
+        amount_1_minimum: u64,
+        deadline: i64,
+    ) -> Result<()> {
+        ctx.accounts.increase_liquidity(
+            amount_0_desired,
+            amount_1_desired,
+            amount_0_minimum,
+            amount_1_minimum,
+            deadline,
+            ctx.remaining_accounts,
+        )
+    }
+
+    /// Removes liquidity from a deposited position, while the deposit stays staked.
+    ///
+    /// Stakes are passed as remaining accounts like in `increase_liquidity`.
+    pub fn decrease_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, DecreaseLiquidity<'info>>,
+        liquidity: u64,
+        amount_0_minimum: u64,
+        amount_1_minimum: u64,
+        deadline: i64,
+    ) -> Result<()> {
+        ctx.accounts.decrease_liquidity(
+            *ctx.bumps.get("stake_manager").unwrap(),
+            liquidity,
+            amount_0_minimum,
+            amount_1_minimum,
+            deadline,
+            ctx.remaining_accounts,
+        )
+    }
+
+    /// Creates an empty [Reward] account for a given incentive, token and address.
     pub fn create_reward_account(ctx: Context<CreateRewardAccount>) -> Result<()> {
         ctx.accounts
             .create_reward_account(*ctx.bumps.get("reward").unwrap())
     }
 
-    /// Unstakes a Cykura LP token
-    pub fn unstake_token(ctx: Context<UnstakeToken>) -> Result<()> {
+    /// Unstakes a Cykura LP token from an [Incentive], boosted or otherwise.
+    ///
+    /// The [Reward] accounts for reward tokens other than the first are passed as remaining accounts.
+    pub fn unstake_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnstakeToken<'info>>,
+    ) -> Result<()> {
         let incentive = &ctx.accounts.incentive;
         let block_timestamp = Clock::get().unwrap().unix_timestamp;
 
@@ -165,25 +555,115 @@ pub mod cykura_staker {
             );
         }
 
-        ctx.accounts.unstake_token(block_timestamp)
+        ctx.accounts
+            .unstake_token(block_timestamp, ctx.remaining_accounts)
+    }
+
+    /// Unstakes a Cykura LP token from a boosted [Incentive]. Equivalent to `unstake_token`, and
+    /// kept for clients passing the boost locker and escrow.
+    ///
+    /// The [Reward] accounts for reward tokens other than the first are passed as remaining accounts.
+    pub fn unstake_token_boosted<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnstakeTokenBoosted<'info>>,
+    ) -> Result<()> {
+        let incentive = &ctx.accounts.incentive;
+        let block_timestamp = Clock::get().unwrap().unix_timestamp;
+
+        // anyone can call [cykura_staker::unstake_token_boosted] if the block time is after the end time of the incentive
+        if block_timestamp < incentive.end_time {
+            require!(
+                ctx.accounts.deposit.owner == ctx.accounts.signer.key(),
+                ErrorCode::OnlyOwnerCanUnstakeFromBoostedIncentive
+            );
+        }
+
+        ctx.accounts
+            .unstake_token_boosted(block_timestamp, ctx.remaining_accounts)
+    }
+
+    /// Unstakes a Cykura LP token from every [Incentive] it is staked in, boosted or otherwise.
+    /// The token can be withdrawn afterwards in the same transaction.
+    ///
+    /// Every [Stake] of the deposit is passed as remaining accounts, followed by its [Incentive]
+    /// and the [Reward] accounts of the owner for every reward token of the incentive.
+    pub fn unstake_all<'info>(ctx: Context<'_, '_, '_, 'info, UnstakeAll<'info>>) -> Result<()> {
+        ctx.accounts.unstake_all(ctx.remaining_accounts)?;
+        Ok(())
+    }
+
+    /// Unstakes a Cykura LP token from every [Incentive], claims the rewards of the owner and
+    /// withdraws the token to the recipient `to`, closing the [Deposit].
+    ///
+    /// Every [Stake] of the deposit is passed as remaining accounts as for `unstake_all`,
+    /// followed by the [Reward] accounts to claim as for `claim_rewards_multi`.
+    pub fn exit<'info>(ctx: Context<'_, '_, '_, 'info, Exit<'info>>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        ctx.accounts.exit(
+            *ctx.bumps.get("stake_manager").unwrap(),
+            ctx.remaining_accounts,
+        )
+    }
+
+    /// Credits the rewards accrued by a staked Cykura LP token, without unstaking it
+    pub fn harvest<'info>(ctx: Context<'_, '_, '_, 'info, Harvest<'info>>) -> Result<()> {
+        ctx.accounts.harvest(ctx.remaining_accounts)
     }
 
-    /// Unstakes a Cykura LP token, with rewards boosted by voting power
-    pub fn unstake_token_boosted(ctx: Context<UnstakeTokenBoosted>) -> Result<()> {
-        ctx.accounts.unstake_token_boosted()
+    /// Settles the rewards of a stake and snapshots the current liquidity of its position.
+    /// Anyone can call it.
+    pub fn sync_stake_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, SyncStakeLiquidity<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.sync_stake_liquidity(ctx.remaining_accounts)
     }
 
     /// Transfers `amount_requested` of accrued `reward_token` rewards from the contract to the recipient `to`
     pub fn claim_reward(ctx: Context<ClaimReward>, amount_requested: u64) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
         ctx.accounts
             .claim_reward(amount_requested, *ctx.bumps.get("stake_manager").unwrap())
     }
+
+    /// Claims `amount_requested` of accrued rewards, or all of them if zero, into the signer's
+    /// escrow in the boost locker of the [Incentive], plus the lock bonus of the incentive.
+    /// The lock can optionally be extended to `lock_duration` seconds from now.
+    pub fn claim_and_lock(
+        ctx: Context<ClaimAndLock>,
+        amount_requested: u64,
+        lock_duration: Option<i64>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        ctx.accounts.claim_and_lock(
+            *ctx.bumps.get("stake_manager").unwrap(),
+            Clock::get().unwrap().unix_timestamp,
+            amount_requested,
+            lock_duration,
+        )
+    }
+
+    /// Claims all rewards owed by several [Reward] accounts of the signer.
+    ///
+    /// Every [Reward] account is passed as remaining accounts, followed by its vault, the token
+    /// account receiving the reward and the treasury token account, in groups of four accounts.
+    pub fn claim_rewards_multi<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimRewardsMulti<'info>>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+
+        ctx.accounts.claim_rewards_multi(
+            *ctx.bumps.get("stake_manager").unwrap(),
+            ctx.remaining_accounts,
+        )
+    }
 }
 
 /// [cykura_staker] errors.
 #[error_code]
 pub enum ErrorCode {
-    #[msg("cykura_staker::add_reward: reward must be positive")]
+    #[msg("cykura_staker: reward must be positive")]
     RewardMustBePositive,
     #[msg("cykura_staker::create_incentive: start time must be now or in the future")]
     StartTimeMustBeNowOrInTheFuture,
@@ -195,8 +675,6 @@ pub enum ErrorCode {
     IncentiveDurationIsTooLong,
     #[msg("cykura_staker::end_incentive: cannot end incentive before end time")]
     CannotEndIncentiveBeforeEndTime,
-    #[msg("cykura_staker::end_incentive: no refund available")]
-    NoRefundAvailable,
     #[msg("cykura_staker::end_incentive: cannot end incentive while deposits are staked")]
     CannotEndIncentiveWhileDepositsAreStaked,
     #[msg("cykura_staker::create_deposit: not a Cykura NFT")]
@@ -223,6 +701,92 @@ pub enum ErrorCode {
     NotLatestObservation,
     #[msg("cykura_staker::unstake_token: only owner can withdraw token before end time")]
     OnlyOwnerCanWithdrawTokenBeforeEndTime,
-    #[msg("cykura_staker::unstake_token_boosted: only owner can unstake token from a boosted incentive")]
+    #[msg("cykura_staker::unstake_token_boosted: only owner can unstake token from a boosted incentive before end time")]
     OnlyOwnerCanUnstakeFromBoostedIncentive,
+    #[msg("cykura_staker::harvest: only owner can harvest rewards")]
+    OnlyOwnerCanHarvest,
+    #[msg("cykura_staker::boost: escrow is not the deposit owner's escrow in the boost locker")]
+    InvalidBoostEscrow,
+    #[msg("cykura_staker::create_incentive_boosted: boost base percent must not exceed 100")]
+    BoostBasePercentTooLarge,
+    #[msg("cykura_staker::add_reward_token: cannot add reward token after start time")]
+    CannotAddRewardTokenAfterStartTime,
+    #[msg("cykura_staker::add_reward_token: token is already a reward of the incentive")]
+    DuplicateRewardToken,
+    #[msg("cykura_staker::add_reward_token: too many reward tokens")]
+    TooManyRewardTokens,
+    #[msg("cykura_staker: missing reward account for a reward token of the incentive")]
+    MissingRewardAccount,
+    #[msg("cykura_staker: reward account does not match the incentive, reward token or owner")]
+    InvalidRewardAccount,
+    #[msg("cykura_staker: vault does not match the incentive and reward token")]
+    InvalidVault,
+    #[msg("cykura_staker: token is not a reward of the incentive")]
+    InvalidRewardToken,
+    #[msg("cykura_staker::end_incentive: token account is not owned by the refundee or has the wrong mint")]
+    InvalidRefundeeTokenAccount,
+    #[msg("cykura_staker::create_incentive: emission schedule must be sorted, within the incentive, and emit rewards")]
+    InvalidEmissionSchedule,
+    #[msg("cykura_staker::extend_incentive: cannot extend incentive after end time")]
+    CannotExtendIncentiveAfterEndTime,
+    #[msg("cykura_staker::extend_incentive: end time must be after the current end time")]
+    EndTimeMustBeAfterCurrentEndTime,
+    #[msg("cykura_staker: only the incentive authority can manage the incentive")]
+    OnlyAuthorityCanManageIncentive,
+    #[msg("cykura_staker::cancel_incentive: cannot cancel incentive while deposits are staked")]
+    CannotCancelIncentiveWhileDepositsAreStaked,
+    #[msg("cykura_staker::create_incentive: only the incentive creator can create incentives")]
+    NotIncentiveCreator,
+    #[msg("cykura_staker::set_config: limits must be positive and within their upper bounds")]
+    InvalidConfig,
+    #[msg("cykura_staker::accept_admin: only the pending admin can accept")]
+    OnlyPendingAdminCanAccept,
+    #[msg("cykura_staker: program is paused")]
+    ProgramPaused,
+    #[msg("cykura_staker: incentive is paused")]
+    IncentivePaused,
+    #[msg("cykura_staker: stake account does not match the deposit or incentive")]
+    InvalidStakeAccount,
+    #[msg("cykura_staker: every stake of the deposit must be passed")]
+    MissingStakeAccount,
+    #[msg("cykura_staker::set_protocol_fee: protocol fee too large")]
+    ProtocolFeeTooLarge,
+    #[msg("cykura_staker::claim_reward: token account is not owned by the treasury or has the wrong mint")]
+    InvalidTreasuryTokenAccount,
+    #[msg("cykura_staker: pool is not allowed")]
+    PoolNotAllowed,
+    #[msg("cykura_staker: reward token is not allowed")]
+    RewardTokenNotAllowed,
+    #[msg("cykura_staker::collect_fees: only owner can collect fees")]
+    OnlyOwnerCanCollectFees,
+    #[msg("cykura_staker: only owner can modify liquidity")]
+    OnlyOwnerCanModifyLiquidity,
+    #[msg("cykura_staker::stake_token_multi: boosted incentives must be staked with stake_token_boosted")]
+    CannotStakeMultiInBoostedIncentive,
+    #[msg("cykura_staker::set_lock_bonus: lock bonus is too large")]
+    LockBonusTooLarge,
+    #[msg("cykura_staker::set_lock_bonus: cannot set lock bonus after start time")]
+    CannotSetLockBonusAfterStartTime,
+    #[msg("cykura_staker::claim_and_lock: incentive is not boosted by the locker")]
+    InvalidLocker,
+    #[msg("cykura_staker::claim_and_lock: reward token is not the locker token")]
+    RewardTokenIsNotLockerToken,
+    #[msg("cykura_staker::set_vesting_duration: cannot set vesting duration after start time")]
+    CannotSetVestingDurationAfterStartTime,
+    #[msg(
+        "cykura_staker::set_vesting_duration: vesting duration must be between zero and one year"
+    )]
+    InvalidVestingDuration,
+    #[msg("cykura_staker::set_min_stake_duration: cannot set min stake duration after start time")]
+    CannotSetMinStakeDurationAfterStartTime,
+    #[msg("cykura_staker::set_min_stake_duration: min stake duration must be within the incentive duration and penalty cannot exceed 100%")]
+    InvalidMinStakeDuration,
+    #[msg("cykura_staker::close_vault: incentive has not ended")]
+    IncentiveNotEnded,
+    #[msg("cykura_staker::close_vault: vault still holds rewards within the claim period")]
+    VaultNotEmpty,
+    #[msg("cykura_staker: token is already staked in the incentive")]
+    TokenAlreadyStaked,
+    #[msg("cykura_staker: emission rate must be constant until accrual is checkpointed at emission points")]
+    NonUniformEmissionSchedule,
 }
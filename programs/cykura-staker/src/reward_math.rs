@@ -1,23 +1,35 @@
 ///! Math for computing rewards
 ///! Allows computing rewards given some parameters of stakes and incentives
 ///! Credits for veBoost formula- https://resources.curve.fi/reward-gauges/boosting-your-crv-rewards#formula
+use crate::EmissionPoint;
 use cyclos_core::libraries::full_math::MulDiv;
 
+/// The emission rate of a uniform schedule, in basis points
+pub const UNIFORM_RATE_BPS: u64 = 10_000;
+
 /// Compute the amount of rewards owed given parameters of the incentive and stake
 ///
 /// # Math
 ///
 /// `reward_per_deposit = total_reward * time_contribution_of_deposit / total_time`
 ///
+/// For non-uniform emission schedules, every second is weighted by its emission rate. The pool
+/// only reports the liquidity-seconds of a deposit over the whole accrual window, so they are
+/// assumed to be spread evenly over it and weighted by the average emission rate of the window.
+/// This is exact only while the window lies within one segment of the schedule, which is why
+/// incentives currently reject schedules with a varying rate.
+///
 /// # Arguments
 ///
 /// * `total_reward_unclaimed` - The total amount of unclaimed rewards left for an incentive
-/// * `total_seconds_claimed_x32` - How many full liquidity-seconds have been already claimed for the incentive
+/// * `total_seconds_claimed_x32` - How many full emission-weighted liquidity-seconds have been already claimed for the incentive
 /// * `start_time` - When the incentive rewards began in epoch seconds
 /// * `end_time` - When rewards are no longer being dripped out in epoch seconds
+/// * `emission_schedule` - The emission schedule of the incentive, empty for uniform emissions
 /// * `liquidity` - The amount of liquidity, assumed to be constant over the period over which the snapshots are measured
 /// * `seconds_per_liquidity_inside_initial_x32` - The seconds per liquidity of the liquidity tick range as of the beginning of the period
 /// * `seconds_per_liquidity_inside_x32` - The seconds per liquidity of the liquidity tick range as of the current block timestamp
+/// * `snapshot_time` - The block timestamp at the beginning of the period
 /// * `current_time` - The current block timestamp, which must be greater than or equal to the start time
 ///
 pub fn compute_reward_amount(
@@ -25,24 +37,45 @@ pub fn compute_reward_amount(
     total_seconds_claimed_x32: u64,
     start_time: i64,
     end_time: i64,
+    emission_schedule: &[EmissionPoint],
     liquidity: u64,
     seconds_per_liquidity_inside_initial_x32: u64,
     seconds_per_liquidity_inside_x32: u64,
+    snapshot_time: i64,
     current_time: i64,
 ) -> RewardOwed {
     // this should never be called before the start time
     assert!(current_time >= start_time);
 
+    let total_seconds_unclaimed_x32 = u64::try_from(
+        (compute_emissions(emission_schedule, start_time, end_time.max(current_time)) << 32)
+            / u128::from(UNIFORM_RATE_BPS),
+    )
+    .unwrap()
+        - total_seconds_claimed_x32;
+
     // this operation is safe, as the difference cannot be greater than 1/stake.liquidity
     let seconds_inside_x32 =
         (seconds_per_liquidity_inside_x32 - seconds_per_liquidity_inside_initial_x32) * liquidity;
 
-    let total_seconds_unclaimed_x32 =
-        ((end_time.max(current_time) - start_time) << 32) as u64 - total_seconds_claimed_x32;
+    // weigh by the average emission rate over the period. This is exact for uniform emissions and
+    // windows within one emission segment, and is capped by the unclaimed seconds otherwise.
+    let seconds_inside_x32 = if current_time > snapshot_time {
+        let weighted_seconds_inside_x32 = u128::from(seconds_inside_x32)
+            * compute_emissions(emission_schedule, snapshot_time, current_time)
+            / (u128::from(UNIFORM_RATE_BPS) * (current_time - snapshot_time) as u128);
+        weighted_seconds_inside_x32.min(total_seconds_unclaimed_x32.into()) as u64
+    } else {
+        0
+    };
 
-    let reward = total_reward_unclaimed
-        .mul_div_floor(seconds_inside_x32, total_seconds_unclaimed_x32)
-        .unwrap();
+    let reward = if total_seconds_unclaimed_x32 == 0 {
+        0
+    } else {
+        total_reward_unclaimed
+            .mul_div_floor(seconds_inside_x32, total_seconds_unclaimed_x32)
+            .unwrap()
+    };
 
     RewardOwed {
         reward,
@@ -50,70 +83,126 @@ pub fn compute_reward_amount(
     }
 }
 
-/// Compute the amount of rewards owed given parameters of the incentive, stake and voting power
+/// Compute the emissions of a schedule over a time window, in basis point-seconds
+///
+/// The emission rate is interpolated linearly between the points of the schedule, and is constant
+/// before the first and after the last point. An empty schedule emits at [UNIFORM_RATE_BPS].
+///
+/// # Arguments
+///
+/// * `emission_schedule` - The emission points, sorted by time
+/// * `from` - The beginning of the window in epoch seconds
+/// * `to` - The end of the window in epoch seconds
+///
+pub fn compute_emissions(emission_schedule: &[EmissionPoint], from: i64, to: i64) -> u128 {
+    let (first, last) = match (emission_schedule.first(), emission_schedule.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return u128::from(UNIFORM_RATE_BPS) * overlap(i64::MIN, i64::MAX, from, to),
+    };
+
+    // areas are doubled to keep the trapezoids exact
+    let mut emissions_x2 = 2 * u128::from(first.rate_bps) * overlap(i64::MIN, first.time, from, to)
+        + 2 * u128::from(last.rate_bps) * overlap(last.time, i64::MAX, from, to);
+
+    for segment in emission_schedule.windows(2) {
+        let (a, b) = (&segment[0], &segment[1]);
+        let segment_from = a.time.max(from);
+        let segment_to = b.time.min(to);
+        if segment_to <= segment_from {
+            continue;
+        }
+
+        let rate_at = |time: i64| {
+            let (rate_a, rate_b) = (i128::from(a.rate_bps), i128::from(b.rate_bps));
+            (rate_a + (rate_b - rate_a) * i128::from(time - a.time) / i128::from(b.time - a.time))
+                as u128
+        };
+        emissions_x2 +=
+            (rate_at(segment_from) + rate_at(segment_to)) * (segment_to - segment_from) as u128;
+    }
+
+    emissions_x2 / 2
+}
+
+/// The length of the overlap of `[a, b]` and `[from, to]`
+fn overlap(a: i64, b: i64, from: i64, to: i64) -> u128 {
+    let start = a.max(from);
+    let end = b.min(to);
+    if end > start {
+        (end - start) as u128
+    } else {
+        0
+    }
+}
+
+/// Compute the liquidity of a stake after applying the boost from voting power
 ///
 /// # Math
 ///
 /// * A dampening factor for liquidity is applied as below, where `adjusted_liquidity <= liquidity`.
-/// `adjusted_liquidity = min (0.4 * liquidity + 0.6 * total_liquidity * voting_power / max_voting_power, liquidity)`
+/// `adjusted_liquidity = min (base * liquidity + (1 - base) * total_liquidity * voting_power / total_voting_power, liquidity)`
 ///
 /// * voting_power = amount * max_multiplier * seconds_till_expiry / max_duration
 ///
 /// # Arguments
 ///
-/// * `total_reward_unclaimed` - The total amount of unclaimed rewards left for an incentive
-/// * `total_seconds_claimed_x32` - How many full liquidity-seconds have been already claimed for the incentive
-/// * `start_time` - When the incentive rewards began in epoch seconds
-/// * `end_time` - When rewards are no longer being dripped out in epoch seconds
-/// * `liquidity` - The amount of liquidity, assumed to be constant over the period over which the snapshots are measured
-/// * `seconds_per_liquidity_inside_initial_x32` - The seconds per liquidity of the liquidity tick range as of the beginning of the period
-/// * `seconds_per_liquidity_inside_x32` - The seconds per liquidity of the liquidity tick range as of the current block timestamp
-/// * `current_time` - The current block timestamp, which must be greater than or equal to the start time
+/// * `liquidity` - The liquidity of the stake
+/// * `total_pool_liquidity` - The total pool liquidity
 /// * `voting_power` - The voting power in terms of locked veCYS
-/// * `total_voting_power` - The total voting power, when the entire supply is locked for the max duration.
-/// * `pool_liquidity` - The total pool liquidity
+/// * `total_voting_power` - The total voting power the stake's voting power is measured against
+/// * `base_percent` - The percentage of liquidity which earns rewards without voting power, 40 in the Izumi formula
 ///
-pub fn compute_reward_amount_boosted(
-    total_reward_unclaimed: u64,
-    total_seconds_claimed_x32: u64,
-    start_time: i64,
-    end_time: i64,
+pub fn compute_effective_liquidity(
     liquidity: u64,
-    seconds_per_liquidity_inside_initial_x32: u64,
-    seconds_per_liquidity_inside_x32: u64,
-    current_time: i64,
     total_pool_liquidity: u64,
     voting_power: u64,
     total_voting_power: u64,
-) -> RewardOwed {
-    // this should never be called before the start time
-    assert!(current_time >= start_time);
+    base_percent: u8,
+) -> u64 {
+    let base_percent = u64::from(base_percent);
+    let base = liquidity.mul_div_floor(base_percent, 100).unwrap();
 
-    let effective_liquidity = std::cmp::min(
-        liquidity * 4 / 10
-            + total_pool_liquidity
-                .mul_div_floor(voting_power, total_voting_power)
-                .unwrap()
-                * 6
-                / 10,
-        liquidity,
-    );
+    // the voting power share is capped at the stake liquidity, which leaves the minimum unchanged
+    let boost = if total_voting_power == 0 {
+        0
+    } else {
+        total_pool_liquidity
+            .mul_div_floor(voting_power, total_voting_power)
+            .unwrap_or(u64::MAX)
+            .min(liquidity)
+            .mul_div_floor(100 - base_percent, 100)
+            .unwrap()
+    };
 
-    // this operation is safe, as the difference cannot be greater than 1/stake.liquidity
-    let seconds_inside_x32 = (seconds_per_liquidity_inside_x32
-        - seconds_per_liquidity_inside_initial_x32)
-        * effective_liquidity;
-
-    let total_seconds_unclaimed_x32 =
-        ((end_time.max(current_time) - start_time) << 32) as u64 - total_seconds_claimed_x32;
-
-    let reward = total_reward_unclaimed
-        .mul_div_floor(seconds_inside_x32, total_seconds_unclaimed_x32)
-        .unwrap();
+    std::cmp::min(base + boost, liquidity)
+}
 
-    RewardOwed {
-        reward,
-        seconds_inside_x32,
+/// Compute the amount of a linearly vesting tranche which has vested
+///
+/// # Arguments
+///
+/// * `amount` - The amount of the tranche
+/// * `start_time` - When the tranche begins to vest
+/// * `end_time` - When the tranche is fully vested
+/// * `current_time` - The current block timestamp
+///
+pub fn compute_vested_amount(
+    amount: u64,
+    start_time: i64,
+    end_time: i64,
+    current_time: i64,
+) -> u64 {
+    if current_time >= end_time {
+        amount
+    } else if current_time <= start_time {
+        0
+    } else {
+        amount
+            .mul_div_floor(
+                (current_time - start_time) as u64,
+                (end_time - start_time) as u64,
+            )
+            .unwrap()
     }
 }
 
@@ -125,3 +214,62 @@ pub struct RewardOwed {
     /// The total liquidity seconds inside the position's range for the duration of the stake.
     pub seconds_inside_x32: u64,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn uniform_schedule_matches_duration() {
+        assert_eq!(
+            compute_emissions(&[], 100, 160),
+            60 * u128::from(UNIFORM_RATE_BPS)
+        );
+    }
+
+    #[test]
+    fn step_and_linear_schedule() {
+        // 2x for the first 10 seconds, then decaying linearly from 1x to 0 over 10 seconds
+        let schedule = [
+            EmissionPoint {
+                time: 0,
+                rate_bps: 20_000,
+            },
+            EmissionPoint {
+                time: 10,
+                rate_bps: 20_000,
+            },
+            EmissionPoint {
+                time: 10,
+                rate_bps: 10_000,
+            },
+            EmissionPoint {
+                time: 20,
+                rate_bps: 0,
+            },
+        ];
+
+        assert_eq!(compute_emissions(&schedule, 0, 10), 200_000);
+        assert_eq!(compute_emissions(&schedule, 10, 20), 50_000);
+        assert_eq!(compute_emissions(&schedule, 5, 15), 100_000 + 37_500);
+        assert_eq!(compute_emissions(&schedule, 20, 30), 0);
+    }
+
+    #[test]
+    fn uniform_reward_is_proportional() {
+        let RewardOwed {
+            reward,
+            seconds_inside_x32,
+        } = compute_reward_amount(1_000, 0, 0, 100, &[], 1, 0, 25 << 32, 0, 50);
+
+        assert_eq!(seconds_inside_x32, 25 << 32);
+        assert_eq!(reward, 250);
+    }
+
+    #[test]
+    fn linear_vesting() {
+        assert_eq!(compute_vested_amount(1000, 100, 200, 50), 0);
+        assert_eq!(compute_vested_amount(1000, 100, 200, 125), 250);
+        assert_eq!(compute_vested_amount(1000, 100, 200, 300), 1000);
+    }
+}
@@ -0,0 +1,531 @@
+//! Crediting accrued rewards to [Reward] accounts.
+
+use crate::ErrorCode;
+use crate::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::AccountsExit;
+use anchor_spl::token;
+use cyclos_core::libraries::full_math::MulDiv;
+use std::mem::size_of;
+
+/// Credits the rewards accrued by a stake to the [Reward] accounts of the deposit owner. Rewards
+/// of an incentive with vesting are added as a new vesting tranche.
+///
+/// The [Reward] account of the first reward token is validated by the instruction accounts. The
+/// [Reward] accounts of the other reward tokens are passed in order as remaining accounts.
+///
+/// # Arguments
+///
+/// * `incentive` - The incentive paying out the rewards
+/// * `owner` - The owner of the deposit
+/// * `rewards` - The rewards owed for every reward token of the incentive
+/// * `current_time` - The current block timestamp, from which vesting rewards vest
+/// * `reward` - The [Reward] account of the first reward token
+/// * `remaining_accounts` - The [Reward] accounts of the other reward tokens
+///
+pub fn credit_rewards<'info>(
+    incentive: &Account<'info, Incentive>,
+    owner: &Pubkey,
+    rewards: &[u64; MAX_REWARD_TOKENS],
+    current_time: i64,
+    reward: &mut Account<'info, Reward>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    reward.credit(rewards[0], incentive.vesting_duration, current_time)?;
+
+    let reward_token_count = incentive.reward_token_count();
+    require!(
+        remaining_accounts.len() >= reward_token_count - 1,
+        ErrorCode::MissingRewardAccount
+    );
+    for (index, account_info) in remaining_accounts[..reward_token_count - 1]
+        .iter()
+        .enumerate()
+    {
+        let mut reward = Account::<Reward>::try_from(account_info)?;
+        require!(
+            reward.incentive == incentive.key()
+                && reward.reward_token == incentive.rewards[index + 1].mint
+                && reward.owner == *owner,
+            ErrorCode::InvalidRewardAccount
+        );
+
+        reward.credit(rewards[index + 1], incentive.vesting_duration, current_time)?;
+        reward.exit(&crate::id())?;
+    }
+
+    Ok(())
+}
+
+/// Pays out the rewards owed by [Reward] accounts from the incentive vaults.
+pub struct RewardPayout<'a, 'info> {
+    /// The program [Config], which sets the protocol fee.
+    pub config: &'a Config,
+
+    /// The root program account which acts as the vault authority.
+    pub stake_manager: AccountInfo<'info>,
+
+    /// Token program.
+    pub token_program: AccountInfo<'info>,
+
+    /// The bump of the stake manager.
+    pub bump: u8,
+}
+
+impl<'a, 'info> RewardPayout<'a, 'info> {
+    /// Transfers `amount_requested` of the rewards owed, or all of them if zero, to `to`. The
+    /// protocol fee is sent to the treasury.
+    ///
+    /// The vault must be the vault of the reward token.
+    ///
+    /// # Arguments
+    ///
+    /// * `reward` - The [Reward] account to claim from
+    /// * `vault` - The vault of the reward token
+    /// * `to` - The token account receiving the reward
+    /// * `treasury_token_account` - The token account of the treasury receiving the protocol fee
+    /// * `amount_requested` - The amount to claim, or zero to claim everything owed
+    ///
+    pub fn pay(
+        &self,
+        reward: &mut Account<'info, Reward>,
+        vault: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        treasury_token_account: &AccountInfo<'info>,
+        amount_requested: u64,
+    ) -> Result<()> {
+        let (amount, fee) =
+            self.withdraw(reward, vault, treasury_token_account, amount_requested)?;
+        self.transfer(vault, to, amount - fee)?;
+
+        emit!(RewardClaimed {
+            to: to.key(),
+            reward: amount,
+            fee,
+        });
+
+        Ok(())
+    }
+
+    /// Releases the vested rewards of a [Reward] account, then deducts `amount_requested` of the
+    /// rewards owed, or all of them if zero, and sends the protocol fee to the treasury. Returns
+    /// the amount deducted and the fee, the difference being left in the vault for the caller to
+    /// pay out.
+    ///
+    /// Claims are only blocked by the program-level pause, so that an incentive authority cannot
+    /// withhold rewards which were already credited.
+    ///
+    /// # Arguments
+    ///
+    /// * `reward` - The [Reward] account to claim from
+    /// * `vault` - The vault of the reward token
+    /// * `treasury_token_account` - The token account of the treasury receiving the protocol fee
+    /// * `amount_requested` - The amount to claim, or zero to claim everything owed
+    ///
+    pub fn withdraw(
+        &self,
+        reward: &mut Account<'info, Reward>,
+        vault: &AccountInfo<'info>,
+        treasury_token_account: &AccountInfo<'info>,
+        amount_requested: u64,
+    ) -> Result<(u64, u64)> {
+        let current_time = Clock::get()?.unix_timestamp;
+        reward.release(current_time);
+        let mut amount = reward.rewards_owed;
+        if amount_requested > 0 && amount_requested < amount {
+            amount = amount_requested;
+        }
+        reward.rewards_owed -= amount;
+
+        let fee = self.config.protocol_fee(amount, current_time);
+        if fee > 0 {
+            let treasury_account = Account::<TokenAccount>::try_from(treasury_token_account)?;
+            require!(
+                treasury_account.owner == self.config.treasury
+                    && treasury_account.mint == reward.reward_token,
+                ErrorCode::InvalidTreasuryTokenAccount
+            );
+            self.transfer(vault, treasury_token_account, fee)?;
+        }
+
+        Ok((amount, fee))
+    }
+
+    /// Transfers all rewards owed by every [Reward] account of `owner` passed in the remaining
+    /// accounts.
+    ///
+    /// The remaining accounts are groups of a [Reward] account, the vault of its reward token, the
+    /// token account where the reward will be sent and the token account of the treasury. The
+    /// treasury token account is needed to charge the protocol fee.
+    pub fn pay_all(&self, owner: &Pubkey, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        require!(
+            remaining_accounts.len() % 4 == 0,
+            ErrorCode::MissingRewardAccount
+        );
+
+        for accounts in remaining_accounts.chunks(4) {
+            let mut reward = Account::<Reward>::try_from(&accounts[0])?;
+            let vault = &accounts[1];
+            require!(reward.owner == *owner, ErrorCode::InvalidRewardAccount);
+            let (vault_address, _) = Pubkey::find_program_address(
+                &[
+                    b"Vault".as_ref(),
+                    reward.incentive.as_ref(),
+                    reward.reward_token.as_ref(),
+                ],
+                &crate::id(),
+            );
+            require!(vault.key() == vault_address, ErrorCode::InvalidVault);
+
+            self.pay(&mut reward, vault, &accounts[2], &accounts[3], 0)?;
+            reward.exit(&crate::id())?;
+        }
+
+        Ok(())
+    }
+
+    /// Transfers reward tokens out of a vault
+    fn transfer(
+        &self,
+        vault: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let seeds: [&[u8]; 1] = [&[self.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                token::Transfer {
+                    from: vault.clone(),
+                    to: to.clone(),
+                    authority: self.stake_manager.clone(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )
+    }
+}
+
+/// The accounts of a stake of a deposit, passed in the remaining accounts as the [Stake], its
+/// [Incentive] and the [Reward] accounts of the deposit owner for every reward token of the
+/// incentive.
+pub struct StakeAccounts<'a, 'info> {
+    /// The stake.
+    pub stake: Account<'info, Stake>,
+
+    /// The incentive of the stake.
+    pub incentive: Account<'info, Incentive>,
+
+    /// The [Reward] account of the first reward token.
+    pub reward: Account<'info, Reward>,
+
+    /// The [Reward] accounts of the other reward tokens.
+    pub other_rewards: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> StakeAccounts<'a, 'info> {
+    /// Loads and validates the accounts of the next stake of a deposit, advancing `accounts`
+    /// past them. Every incentive can be passed only once.
+    ///
+    /// # Arguments
+    ///
+    /// * `deposit` - The staked deposit
+    /// * `accounts` - The remaining accounts, starting at the next stake
+    /// * `incentives` - The incentives of the stakes loaded so far
+    ///
+    pub fn next(
+        deposit: &Deposit,
+        accounts: &mut &'a [AccountInfo<'info>],
+        incentives: &mut Vec<Pubkey>,
+    ) -> Result<Self> {
+        let remaining = *accounts;
+        require!(remaining.len() >= 3, ErrorCode::MissingStakeAccount);
+        let stake = Account::<Stake>::try_from(&remaining[0])?;
+        let incentive = Account::<Incentive>::try_from(&remaining[1])?;
+        require!(
+            stake.mint == deposit.mint
+                && stake.incentive == incentive.key()
+                && !incentives.contains(&incentive.key()),
+            ErrorCode::InvalidStakeAccount
+        );
+        incentives.push(incentive.key());
+
+        let reward_token_count = incentive.reward_token_count();
+        require!(
+            remaining.len() >= 2 + reward_token_count,
+            ErrorCode::MissingRewardAccount
+        );
+        let reward = Account::<Reward>::try_from(&remaining[2])?;
+        require!(
+            reward.incentive == incentive.key()
+                && reward.reward_token == incentive.rewards[0].mint
+                && reward.owner == deposit.owner,
+            ErrorCode::InvalidRewardAccount
+        );
+
+        let other_rewards = &remaining[3..2 + reward_token_count];
+        *accounts = &remaining[2 + reward_token_count..];
+
+        Ok(Self {
+            stake,
+            incentive,
+            reward,
+            other_rewards,
+        })
+    }
+
+    /// Credits the rewards accrued by the stake since its last snapshot, and persists the
+    /// incentive and reward accounts. Returns the rewards credited.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The owner of the deposit
+    /// * `seconds_per_liquidity_inside_x32` - The seconds per liquidity of the position range as of now
+    /// * `unstake` - Whether the stake is being removed
+    /// * `current_time` - The current block timestamp
+    ///
+    pub fn settle(
+        &mut self,
+        owner: &Pubkey,
+        seconds_per_liquidity_inside_x32: u64,
+        unstake: bool,
+        current_time: i64,
+    ) -> Result<[u64; MAX_REWARD_TOKENS]> {
+        let rewards = self.incentive.accrue_reward(
+            self.stake.effective_liquidity,
+            self.stake.seconds_per_liquidity_inside_initial_x32,
+            seconds_per_liquidity_inside_x32,
+            self.stake.snapshot_time,
+            current_time,
+        );
+        let rewards = self.incentive.apply_min_stake_duration(
+            &mut self.stake,
+            rewards,
+            unstake,
+            current_time,
+        );
+        credit_rewards(
+            &self.incentive,
+            owner,
+            &rewards,
+            current_time,
+            &mut self.reward,
+            self.other_rewards,
+        )?;
+
+        self.incentive.exit(&crate::id())?;
+        self.reward.exit(&crate::id())?;
+
+        Ok(rewards)
+    }
+}
+
+/// Settles the rewards of every stake of a deposit at its recorded liquidity, then snapshots the
+/// new liquidity of the position.
+///
+/// Every stake of the deposit is passed in the remaining accounts as [StakeAccounts]. Boosted
+/// stakes keep their boost ratio until they are poked.
+///
+/// # Arguments
+///
+/// * `deposit` - The deposit whose liquidity changed
+/// * `liquidity` - The new liquidity of the position
+/// * `seconds_per_liquidity_inside_x32` - The seconds per liquidity of the position range as of now
+/// * `current_time` - The current block timestamp
+/// * `remaining_accounts` - The stakes of the deposit
+///
+pub fn settle_stakes<'info>(
+    deposit: &Deposit,
+    liquidity: u64,
+    seconds_per_liquidity_inside_x32: u64,
+    current_time: i64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let mut accounts = remaining_accounts;
+    let mut incentives = Vec::with_capacity(deposit.number_of_stakes as usize);
+
+    for _ in 0..deposit.number_of_stakes {
+        let mut stake_accounts = StakeAccounts::next(deposit, &mut accounts, &mut incentives)?;
+        stake_accounts.settle(
+            &deposit.owner,
+            seconds_per_liquidity_inside_x32,
+            false,
+            current_time,
+        )?;
+
+        let stake = &mut stake_accounts.stake;
+        stake.seconds_per_liquidity_inside_initial_x32 = seconds_per_liquidity_inside_x32;
+        stake.snapshot_time = current_time;
+        stake.effective_liquidity =
+            if stake_accounts.incentive.boost_locker.is_some() && stake.liquidity > 0 {
+                stake
+                    .effective_liquidity
+                    .mul_div_floor(liquidity, stake.liquidity)
+                    .unwrap()
+            } else {
+                liquidity
+            };
+        stake.liquidity = liquidity;
+        stake.exit(&crate::id())?;
+    }
+
+    Ok(())
+}
+
+/// Stakes a deposit in every unboosted incentive passed in the remaining accounts, creating a
+/// [Stake] account for each.
+///
+/// The remaining accounts are pairs of an [Incentive] and the [Stake] PDA of the deposit in it.
+///
+/// # Arguments
+///
+/// * `deposit` - The deposit to stake
+/// * `pool` - The pool of the deposited position
+/// * `payer` - The account paying for the stake accounts
+/// * `system_program` - System program
+/// * `liquidity` - The liquidity of the position
+/// * `seconds_per_liquidity_inside_x32` - The seconds per liquidity of the position range as of now
+/// * `remaining_accounts` - The incentives and stakes
+///
+pub fn create_stakes<'info>(
+    deposit: &mut Deposit,
+    pool: Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    liquidity: u64,
+    seconds_per_liquidity_inside_x32: u64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    require!(
+        !remaining_accounts.is_empty() && remaining_accounts.len() % 2 == 0,
+        ErrorCode::MissingStakeAccount
+    );
+    let block_timestamp = Clock::get().unwrap().unix_timestamp;
+
+    for accounts in remaining_accounts.chunks(2) {
+        let mut incentive = Account::<Incentive>::try_from(&accounts[0])?;
+        require!(
+            incentive.pool == pool,
+            ErrorCode::TokenPoolIsNotTheIncentivePool
+        );
+        require!(
+            incentive.boost_locker.is_none(),
+            ErrorCode::CannotStakeMultiInBoostedIncentive
+        );
+        incentive.validate_stake(block_timestamp)?;
+
+        let stake_info = &accounts[1];
+        let (stake_address, bump) = Pubkey::find_program_address(
+            &[
+                b"Stake".as_ref(),
+                deposit.mint.as_ref(),
+                incentive.key().as_ref(),
+            ],
+            &crate::id(),
+        );
+        require!(
+            stake_info.key() == stake_address,
+            ErrorCode::InvalidStakeAccount
+        );
+
+        // an existing stake is owned by this program, whereas lamports sent to the address
+        // beforehand leave it owned by the system program
+        require!(
+            stake_info.owner != &crate::id(),
+            ErrorCode::TokenAlreadyStaked
+        );
+        create_stake_account(
+            stake_info,
+            payer,
+            system_program,
+            &[
+                b"Stake".as_ref(),
+                deposit.mint.as_ref(),
+                incentive.key().as_ref(),
+                &[bump],
+            ],
+        )?;
+
+        let mut stake = Account::<Stake>::try_from_unchecked(stake_info)?;
+        stake.bump = bump;
+        stake.mint = deposit.mint;
+        stake.incentive = incentive.key();
+        stake.seconds_per_liquidity_inside_initial_x32 = seconds_per_liquidity_inside_x32;
+        stake.snapshot_time = block_timestamp;
+        stake.staked_at = block_timestamp;
+        stake.liquidity = liquidity;
+        stake.effective_liquidity = liquidity;
+        stake.exit(&crate::id())?;
+
+        deposit.number_of_stakes = deposit.number_of_stakes.checked_add(1).unwrap();
+        incentive.number_of_stakes = incentive.number_of_stakes.checked_add(1).unwrap();
+        incentive.exit(&crate::id())?;
+
+        emit!(StakeTokenEvent {
+            mint: deposit.mint,
+            incentive: incentive.key(),
+            liquidity: stake.liquidity,
+            effective_liquidity: stake.effective_liquidity,
+        });
+    }
+
+    Ok(())
+}
+
+/// Creates the account of a [Stake] PDA like Anchor's `init`. If lamports were sent to the
+/// address beforehand, `create_account` would fail, so the rent is topped up and the account
+/// allocated and assigned instead.
+///
+/// # Arguments
+///
+/// * `stake_info` - The uninitialized stake PDA
+/// * `payer` - The account paying the rent
+/// * `system_program` - System program
+/// * `seeds` - The seeds and bump of the stake PDA
+///
+fn create_stake_account<'info>(
+    stake_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let space = 8 + size_of::<Stake>();
+    let rent = Rent::get()?.minimum_balance(space);
+    let lamports = stake_info.lamports();
+
+    if lamports == 0 {
+        invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                stake_info.key,
+                rent,
+                space as u64,
+                &crate::id(),
+            ),
+            &[payer.clone(), stake_info.clone(), system_program.clone()],
+            &[seeds],
+        )?;
+        return Ok(());
+    }
+
+    if lamports < rent {
+        invoke(
+            &system_instruction::transfer(payer.key, stake_info.key, rent - lamports),
+            &[payer.clone(), stake_info.clone(), system_program.clone()],
+        )?;
+    }
+    invoke_signed(
+        &system_instruction::allocate(stake_info.key, space as u64),
+        &[stake_info.clone(), system_program.clone()],
+        &[seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(stake_info.key, &crate::id()),
+        &[stake_info.clone(), system_program.clone()],
+        &[seeds],
+    )?;
+
+    Ok(())
+}
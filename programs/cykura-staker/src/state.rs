@@ -1,7 +1,168 @@
 //! State accounts.
 
+use crate::ErrorCode;
 use crate::*;
 
+/// The max number of tokens an [Incentive] can distribute as rewards.
+pub const MAX_REWARD_TOKENS: usize = 3;
+
+/// The max number of points in the emission schedule of an [Incentive].
+pub const MAX_EMISSION_POINTS: usize = 8;
+
+/// The max number of vesting tranches tracked by a [Reward] account. Further rewards are merged
+/// into the latest tranche.
+pub const MAX_VESTING_TRANCHES: usize = 8;
+
+/// The global configuration of the program. The Cykura core program is not configurable, and
+/// stays pinned to [cyclos_core::ID].
+#[account]
+#[derive(Debug)]
+pub struct Config {
+    /// The PDA bump.
+    pub bump: u8,
+
+    /// The address which can update the configuration.
+    pub admin: Pubkey,
+
+    /// The address nominated to become the admin, until it accepts.
+    pub pending_admin: Option<Pubkey>,
+
+    /// The max duration of an incentive in seconds.
+    pub max_incentive_duration: i64,
+
+    /// The max amount of seconds into the future the incentive start_time can be set.
+    pub max_incentive_start_lead_time: i64,
+
+    /// The only address which can create incentives. Anyone can create incentives if not set.
+    pub incentive_creator: Option<Pubkey>,
+
+    /// Whether staking, adding rewards and claiming rewards are paused for every incentive.
+    pub paused: bool,
+
+    /// The protocol fee taken from claimed rewards, in basis points.
+    pub fee_bps: u16,
+
+    /// The increased protocol fee replacing `fee_bps` once the timelock expires, if any.
+    pub pending_fee_bps: Option<u16>,
+
+    /// The time when the pending protocol fee takes effect.
+    pub pending_fee_effective_at: i64,
+
+    /// The wallet whose token accounts receive the protocol fee.
+    pub treasury: Pubkey,
+
+    /// Whether incentives must use an allowed pool and reward token, unless created as permissionless.
+    pub allowlist_enabled: bool,
+}
+
+impl Config {
+    /// Validates the start and end time of a new incentive.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - The time when the incentive program begins
+    /// * `end_time` - The time when rewards stop accruing
+    /// * `current_time` - The current block timestamp
+    ///
+    pub fn validate_incentive_times(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        current_time: i64,
+    ) -> Result<()> {
+        require!(
+            current_time < start_time,
+            ErrorCode::StartTimeMustBeNowOrInTheFuture
+        );
+        require!(
+            start_time - current_time <= self.max_incentive_start_lead_time,
+            ErrorCode::StartTimeTooFarIntoFuture
+        );
+        require!(
+            start_time < end_time,
+            ErrorCode::StartTimeMustBeBeforeEndTime
+        );
+        require!(
+            end_time - start_time < self.max_incentive_duration,
+            ErrorCode::IncentiveDurationIsTooLong
+        );
+
+        Ok(())
+    }
+
+    /// The protocol fee in effect at `current_time`, in basis points.
+    pub fn fee_bps_at(&self, current_time: i64) -> u16 {
+        match self.pending_fee_bps {
+            Some(pending_fee_bps) if current_time >= self.pending_fee_effective_at => {
+                pending_fee_bps
+            }
+            _ => self.fee_bps,
+        }
+    }
+
+    /// The protocol fee on an amount of rewards claimed at `current_time`.
+    pub fn protocol_fee(&self, reward: u64, current_time: i64) -> u64 {
+        (u128::from(reward) * u128::from(self.fee_bps_at(current_time)) / 10_000) as u64
+    }
+
+    /// Validates the pool and reward token of a new incentive against the allowlists. Returns
+    /// whether the incentive is permissionless, i.e. not vetted by the allowlists.
+    ///
+    /// # Arguments
+    ///
+    /// * `permissionless` - Whether the incentive is created as permissionless
+    /// * `allowed_pool` - The [AllowedPool] marker of the pool
+    /// * `allowed_reward_token` - The [AllowedMint] marker of the reward token
+    ///
+    pub fn validate_allowlist(
+        &self,
+        permissionless: bool,
+        allowed_pool: &AccountInfo,
+        allowed_reward_token: &AccountInfo,
+    ) -> Result<bool> {
+        if permissionless || !self.allowlist_enabled {
+            return Ok(true);
+        }
+
+        require!(
+            Account::<AllowedPool>::try_from(allowed_pool).is_ok(),
+            ErrorCode::PoolNotAllowed
+        );
+        require!(
+            Account::<AllowedMint>::try_from(allowed_reward_token).is_ok(),
+            ErrorCode::RewardTokenNotAllowed
+        );
+
+        Ok(false)
+    }
+
+    /// Whether `creator` is allowed to create incentives.
+    pub fn can_create_incentive(&self, creator: &Pubkey) -> bool {
+        self.incentive_creator
+            .map_or(true, |incentive_creator| incentive_creator == *creator)
+    }
+}
+
+/// Marks a pool as allowed for incentives.
+#[account]
+pub struct AllowedPool {
+    /// The PDA bump.
+    pub bump: u8,
+
+    /// The Cykura pool.
+    pub pool: Pubkey,
+}
+
+/// Marks a token as allowed for incentive rewards.
+#[account]
+pub struct AllowedMint {
+    /// The PDA bump.
+    pub bump: u8,
+
+    /// The token mint.
+    pub mint: Pubkey,
+}
+
 /// Represents a staking incentive.
 #[account]
 #[derive(Debug)]
@@ -9,8 +170,9 @@ pub struct Incentive {
     /// The ATA bump.
     pub bump: u8,
 
-    /// The token being distributed as a reward.
-    pub reward_token: Pubkey,
+    /// The tokens being distributed as rewards. The first token is the one used to derive the
+    /// incentive address, and unused slots are left empty.
+    pub rewards: [IncentiveReward; MAX_REWARD_TOKENS],
 
     /// The Cykura pool.
     pub pool: Pubkey,
@@ -18,14 +180,20 @@ pub struct Incentive {
     /// The address which receives any remaining reward tokens when the incentive is ended.
     pub refundee: Pubkey,
 
+    /// The address which can extend or cancel the incentive.
+    pub authority: Pubkey,
+
     /// The time when the incentive program begins.
     pub start_time: i64,
 
     /// The time when rewards stop accruing.
     pub end_time: i64,
 
-    /// The amount of reward token not yet claimed by users
-    pub total_reward_unclaimed: u64,
+    /// The emission schedule of the incentive. Rewards are emitted uniformly if no points are set.
+    pub emission_schedule: [EmissionPoint; 8],
+
+    /// The number of points in the emission schedule.
+    pub emission_point_count: u8,
 
     /// Total liquidity-seconds claimed, represented as a UQ32.32
     pub total_seconds_claimed_x32: u64,
@@ -35,6 +203,74 @@ pub struct Incentive {
 
     /// The Tribeca locker to calculate reward boost. Boosting is disabled if locker is not provided.
     pub boost_locker: Option<Pubkey>,
+
+    /// The percentage of staked liquidity which earns rewards regardless of voting power.
+    /// 100 for incentives without boost.
+    pub boost_base_percent: u8,
+
+    /// The total voting power against which the voting power of a staker is measured.
+    pub boost_denominator: BoostDenominator,
+
+    /// The sum of voting power snapshotted by the stakes of the incentive.
+    pub total_staked_voting_power: u64,
+
+    /// The account which paid rent for the incentive, and is refunded when it is closed.
+    pub payer: Pubkey,
+
+    /// Whether staking and adding rewards are paused for the incentive. Claims are not paused.
+    pub paused: bool,
+
+    /// Whether the incentive was created without being vetted by the allowlists.
+    pub permissionless: bool,
+
+    /// The bonus paid on rewards claimed into a lock of the boost locker, in basis points of the
+    /// amount claimed. The bonus comes out of the lock bonus reserve of the locker token.
+    pub lock_bonus_bps: u16,
+
+    /// The duration in seconds over which credited rewards vest linearly, zero if rewards are
+    /// claimable immediately.
+    pub vesting_duration: i64,
+
+    /// The minimum duration in seconds a deposit must stay staked to earn its full rewards.
+    pub min_stake_duration: i64,
+
+    /// The share of rewards forfeited back to the incentive when a stake is removed before the
+    /// minimum stake duration, in basis points.
+    pub early_unstake_penalty_bps: u16,
+}
+
+/// A token distributed as a reward by an [Incentive].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct IncentiveReward {
+    /// The token being distributed as a reward. The default pubkey if the slot is unused.
+    pub mint: Pubkey,
+
+    /// The amount of reward token not yet claimed by users
+    pub total_reward_unclaimed: u64,
+
+    /// The amount of reward token deposited to pay lock bonuses, kept apart from the rewards.
+    pub lock_bonus_reserve: u64,
+}
+
+/// A point in the emission schedule of an [Incentive]. The emission rate is interpolated
+/// linearly between consecutive points, so two points at the same time make a step.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct EmissionPoint {
+    /// The time of the point in epoch seconds.
+    pub time: i64,
+
+    /// The emission rate at the point, relative to a uniform schedule in basis points.
+    pub rate_bps: u16,
+}
+
+/// The total voting power used to compute the boost of a stake.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum BoostDenominator {
+    /// The voting power when the entire locked supply is locked for the max duration.
+    MaxVotingPower,
+
+    /// The locked supply of the locker.
+    LockedSupply,
 }
 
 /// Represents a deposited LP.
@@ -72,19 +308,39 @@ pub struct Stake {
     /// The incentive of the [Stake].
     pub incentive: Pubkey,
 
-    /// Seconds per liquidity at the time of staking.
+    /// Seconds per liquidity at the time of staking, or of the last settlement.
     pub seconds_per_liquidity_inside_initial_x32: u64,
 
-    /// Liquidity in the LP NFT.
+    /// The time when `seconds_per_liquidity_inside_initial_x32` was snapshotted.
+    pub snapshot_time: i64,
+
+    /// Liquidity in the LP NFT, as of the last snapshot.
     pub liquidity: u64,
+
+    /// The liquidity earning rewards, after applying the boost as of the last snapshot.
+    /// Equal to `liquidity` for incentives without boost.
+    pub effective_liquidity: u64,
+
+    /// The voting power of the deposit owner as of the last snapshot.
+    pub voting_power: u64,
+
+    /// The time when the deposit was staked.
+    pub staked_at: i64,
+
+    /// The rewards settled before the minimum stake duration of the incentive has passed, held
+    /// back until it passes.
+    pub pending_rewards: [u64; MAX_REWARD_TOKENS],
 }
 
-/// The amounts of reward tokens owed to a given address according to the last time all stakes were updated
+/// The amounts of reward tokens owed by an incentive to a given address according to the last time all stakes were updated
 #[account]
 pub struct Reward {
     /// The ATA bump.
     pub bump: u8,
 
+    /// The [Incentive] paying out the reward.
+    pub incentive: Pubkey,
+
     /// The token being distributed as a reward.
     pub reward_token: Pubkey,
 
@@ -93,4 +349,434 @@ pub struct Reward {
 
     /// The amount of the reward token claimable by the owner
     pub rewards_owed: u64,
+
+    /// Rewards credited by an incentive with vesting, released to `rewards_owed` as they vest.
+    pub vesting: [VestingTranche; MAX_VESTING_TRANCHES],
+}
+
+/// Rewards credited at once, vesting linearly between the start and end time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct VestingTranche {
+    /// The amount not yet released. Zero if the slot is unused.
+    pub amount: u64,
+
+    /// The time from which the amount vests, or when it was last released.
+    pub start_time: i64,
+
+    /// The time when the amount is fully vested.
+    pub end_time: i64,
+}
+
+impl Reward {
+    /// Credits rewards, which vest over `vesting_duration` seconds from now if not zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The amount of rewards credited
+    /// * `vesting_duration` - The vesting duration of the incentive
+    /// * `current_time` - The current block timestamp
+    ///
+    pub fn credit(&mut self, amount: u64, vesting_duration: i64, current_time: i64) -> Result<()> {
+        if amount == 0 || vesting_duration == 0 {
+            self.rewards_owed += amount;
+            return Ok(());
+        }
+        self.release(current_time);
+
+        let tranche = VestingTranche {
+            amount,
+            start_time: current_time,
+            end_time: current_time
+                .checked_add(vesting_duration)
+                .ok_or(ErrorCode::InvalidVestingDuration)?,
+        };
+        match self.vesting.iter_mut().find(|slot| slot.amount == 0) {
+            Some(slot) => *slot = tranche,
+            None => {
+                // merge into the tranche ending last, averaging its times weighted by amount
+                let slot = self
+                    .vesting
+                    .iter_mut()
+                    .max_by_key(|slot| slot.end_time)
+                    .unwrap();
+                let (weight, total) = (slot.amount as i128, slot.amount as i128 + amount as i128);
+                let average = |a: i64, b: i64| {
+                    ((a as i128 * weight + b as i128 * amount as i128) / total) as i64
+                };
+                slot.start_time = average(slot.start_time, tranche.start_time);
+                slot.end_time = average(slot.end_time, tranche.end_time);
+                slot.amount += tranche.amount;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves the vested amount of every tranche to `rewards_owed`.
+    pub fn release(&mut self, current_time: i64) {
+        for slot in self.vesting.iter_mut().filter(|slot| slot.amount > 0) {
+            let vested = reward_math::compute_vested_amount(
+                slot.amount,
+                slot.start_time,
+                slot.end_time,
+                current_time,
+            );
+            self.rewards_owed += vested;
+            slot.amount -= vested;
+            slot.start_time = slot.start_time.max(current_time);
+        }
+    }
+}
+
+impl Incentive {
+    /// The number of reward tokens of the incentive.
+    pub fn reward_token_count(&self) -> usize {
+        self.rewards
+            .iter()
+            .take_while(|reward| reward.mint != Pubkey::default())
+            .count()
+    }
+
+    /// The slot of `mint` in the reward tokens of the incentive.
+    pub fn reward_index(&self, mint: &Pubkey) -> Option<usize> {
+        self.rewards[..self.reward_token_count()]
+            .iter()
+            .position(|reward| reward.mint == *mint)
+    }
+
+    /// Whether any reward token has rewards left to distribute.
+    pub fn has_unclaimed_rewards(&self) -> bool {
+        self.rewards
+            .iter()
+            .any(|reward| reward.total_reward_unclaimed > 0)
+    }
+
+    /// Validates that a deposit can be staked in the incentive at `current_time`.
+    pub fn validate_stake(&self, current_time: i64) -> Result<()> {
+        require!(
+            current_time >= self.start_time,
+            ErrorCode::IncentiveNotStarted
+        );
+        require!(current_time < self.end_time, ErrorCode::IncentiveEnded);
+        require!(
+            self.has_unclaimed_rewards(),
+            ErrorCode::NonExistentIncentive
+        );
+        require!(!self.paused, ErrorCode::IncentivePaused);
+
+        Ok(())
+    }
+
+    /// Sets the emission schedule of the incentive. Points must be sorted by time, lie between
+    /// the start and end time, and emit rewards over the incentive.
+    ///
+    /// The emission rate must be the same at every point. Stakes accrue over windows which can
+    /// span several emission points, and the pool cannot tell how long a position was in range
+    /// within each segment, so rewards would be misattributed under a varying rate.
+    pub fn set_emission_schedule(&mut self, emission_schedule: &[EmissionPoint]) -> Result<()> {
+        require!(
+            emission_schedule.len() <= MAX_EMISSION_POINTS,
+            ErrorCode::InvalidEmissionSchedule
+        );
+        require!(
+            emission_schedule
+                .iter()
+                .all(|point| point.time >= self.start_time && point.time <= self.end_time),
+            ErrorCode::InvalidEmissionSchedule
+        );
+        require!(
+            emission_schedule
+                .windows(2)
+                .all(|points| points[0].time <= points[1].time),
+            ErrorCode::InvalidEmissionSchedule
+        );
+        require!(
+            reward_math::compute_emissions(emission_schedule, self.start_time, self.end_time) > 0,
+            ErrorCode::InvalidEmissionSchedule
+        );
+        require!(
+            emission_schedule
+                .windows(2)
+                .all(|points| points[0].rate_bps == points[1].rate_bps),
+            ErrorCode::NonUniformEmissionSchedule
+        );
+
+        self.emission_schedule[..emission_schedule.len()].copy_from_slice(emission_schedule);
+        self.emission_point_count = emission_schedule.len() as u8;
+
+        Ok(())
+    }
+
+    /// Appends points to the emission schedule when the incentive is extended, and revalidates the
+    /// schedule against the new end time. Points must lie in the future and after the current
+    /// schedule, so rewards already emitted are unchanged. The current rate is pinned at
+    /// `current_time` if needed, and the last rate carries on if no points are given.
+    ///
+    /// # Arguments
+    ///
+    /// * `emission_points` - The points to append, sorted by time
+    /// * `current_time` - The current block timestamp
+    ///
+    pub fn extend_emission_schedule(
+        &mut self,
+        emission_points: &[EmissionPoint],
+        current_time: i64,
+    ) -> Result<()> {
+        let mut emission_schedule =
+            self.emission_schedule[..usize::from(self.emission_point_count)].to_vec();
+        let (last_time, current_rate_bps) = match emission_schedule.last() {
+            Some(last) => (last.time, last.rate_bps),
+            None => (i64::MIN, reward_math::UNIFORM_RATE_BPS as u16),
+        };
+
+        if let Some(first) = emission_points.first() {
+            require!(
+                first.time >= current_time && first.time >= last_time,
+                ErrorCode::InvalidEmissionSchedule
+            );
+            if last_time < current_time {
+                emission_schedule.push(EmissionPoint {
+                    time: current_time,
+                    rate_bps: current_rate_bps,
+                });
+            }
+            emission_schedule.extend_from_slice(emission_points);
+        }
+
+        self.set_emission_schedule(&emission_schedule)
+    }
+
+    /// Settles the rewards accrued by a stake since its last snapshot, and returns the reward owed
+    /// for every reward token.
+    ///
+    /// The claimed liquidity-seconds and the paid out rewards are deducted from the incentive.
+    ///
+    /// # Arguments
+    ///
+    /// * `liquidity` - The liquidity of the stake
+    /// * `seconds_per_liquidity_inside_initial_x32` - The seconds per liquidity snapshot of the stake
+    /// * `seconds_per_liquidity_inside_x32` - The seconds per liquidity of the position range as of now
+    /// * `snapshot_time` - The time of the seconds per liquidity snapshot of the stake
+    /// * `current_time` - The current block timestamp
+    ///
+    pub fn accrue_reward(
+        &mut self,
+        liquidity: u64,
+        seconds_per_liquidity_inside_initial_x32: u64,
+        seconds_per_liquidity_inside_x32: u64,
+        snapshot_time: i64,
+        current_time: i64,
+    ) -> [u64; MAX_REWARD_TOKENS] {
+        let reward_token_count = self.reward_token_count();
+        let mut rewards = [0; MAX_REWARD_TOKENS];
+        let mut seconds_claimed_x32 = 0;
+
+        for (slot, reward_owed) in self.rewards[..reward_token_count]
+            .iter_mut()
+            .zip(rewards.iter_mut())
+        {
+            let reward_math::RewardOwed {
+                reward,
+                seconds_inside_x32,
+            } = reward_math::compute_reward_amount(
+                slot.total_reward_unclaimed,
+                self.total_seconds_claimed_x32,
+                self.start_time,
+                self.end_time,
+                &self.emission_schedule[..usize::from(self.emission_point_count)],
+                liquidity,
+                seconds_per_liquidity_inside_initial_x32,
+                seconds_per_liquidity_inside_x32,
+                snapshot_time,
+                current_time,
+            );
+
+            // reward is never greater than total reward unclaimed
+            slot.total_reward_unclaimed -= reward;
+            *reward_owed = reward;
+            // the claimed liquidity-seconds are the same for every reward token
+            seconds_claimed_x32 = seconds_inside_x32;
+        }
+        self.total_seconds_claimed_x32 += seconds_claimed_x32;
+
+        rewards
+    }
+
+    /// Holds back the rewards settled by a stake until the minimum stake duration has passed, and
+    /// returns the rewards to credit. If the stake is removed earlier, its held back and settled
+    /// rewards lose the early unstake penalty back to the incentive.
+    ///
+    /// Rewards are released without penalty once the incentive has ended, as anyone can then
+    /// unstake the deposit on behalf of its owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `stake` - The settled stake
+    /// * `rewards` - The rewards settled by the stake
+    /// * `unstake` - Whether the stake is being removed
+    /// * `current_time` - The current block timestamp
+    ///
+    pub fn apply_min_stake_duration(
+        &mut self,
+        stake: &mut Stake,
+        mut rewards: [u64; MAX_REWARD_TOKENS],
+        unstake: bool,
+        current_time: i64,
+    ) -> [u64; MAX_REWARD_TOKENS] {
+        for (reward, pending) in rewards.iter_mut().zip(stake.pending_rewards.iter_mut()) {
+            *reward += std::mem::take(pending);
+        }
+        if current_time >= stake.staked_at.saturating_add(self.min_stake_duration)
+            || current_time >= self.end_time
+        {
+            return rewards;
+        }
+        if !unstake {
+            stake.pending_rewards = rewards;
+            return [0; MAX_REWARD_TOKENS];
+        }
+
+        for (slot, reward) in self.rewards.iter_mut().zip(rewards.iter_mut()) {
+            // the penalty is never greater than the reward
+            let penalty =
+                (u128::from(*reward) * u128::from(self.early_unstake_penalty_bps) / 10_000) as u64;
+            slot.total_reward_unclaimed += penalty;
+            *reward -= penalty;
+        }
+
+        rewards
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn reward() -> Reward {
+        Reward {
+            bump: 0,
+            incentive: Pubkey::default(),
+            reward_token: Pubkey::default(),
+            owner: Pubkey::default(),
+            rewards_owed: 0,
+            vesting: [VestingTranche::default(); MAX_VESTING_TRANCHES],
+        }
+    }
+
+    fn incentive(min_stake_duration: i64, early_unstake_penalty_bps: u16) -> Incentive {
+        let mut rewards = [IncentiveReward::default(); MAX_REWARD_TOKENS];
+        rewards[0].mint = Pubkey::new_unique();
+
+        Incentive {
+            bump: 0,
+            rewards,
+            pool: Pubkey::default(),
+            refundee: Pubkey::default(),
+            authority: Pubkey::default(),
+            start_time: 0,
+            end_time: 1000,
+            emission_schedule: [EmissionPoint::default(); MAX_EMISSION_POINTS],
+            emission_point_count: 0,
+            total_seconds_claimed_x32: 0,
+            number_of_stakes: 1,
+            boost_locker: None,
+            boost_base_percent: 100,
+            boost_denominator: BoostDenominator::MaxVotingPower,
+            total_staked_voting_power: 0,
+            payer: Pubkey::default(),
+            paused: false,
+            permissionless: false,
+            lock_bonus_bps: 0,
+            vesting_duration: 0,
+            min_stake_duration,
+            early_unstake_penalty_bps,
+        }
+    }
+
+    fn stake(staked_at: i64) -> Stake {
+        Stake {
+            bump: 0,
+            mint: Pubkey::default(),
+            incentive: Pubkey::default(),
+            seconds_per_liquidity_inside_initial_x32: 0,
+            snapshot_time: staked_at,
+            liquidity: 1,
+            effective_liquidity: 1,
+            voting_power: 0,
+            staked_at,
+            pending_rewards: [0; MAX_REWARD_TOKENS],
+        }
+    }
+
+    #[test]
+    fn credit_without_vesting_is_claimable() {
+        let mut reward = reward();
+        reward.credit(100, 0, 10).unwrap();
+
+        assert_eq!(reward.rewards_owed, 100);
+        assert!(reward.vesting.iter().all(|slot| slot.amount == 0));
+    }
+
+    #[test]
+    fn credit_vests_linearly() {
+        let mut reward = reward();
+        reward.credit(1000, 100, 0).unwrap();
+        assert_eq!(reward.rewards_owed, 0);
+
+        reward.release(25);
+        assert_eq!(reward.rewards_owed, 250);
+
+        reward.release(100);
+        assert_eq!(reward.rewards_owed, 1000);
+        assert!(reward.vesting.iter().all(|slot| slot.amount == 0));
+    }
+
+    #[test]
+    fn credit_merges_tranches_when_full() {
+        let mut reward = reward();
+        for time in 0..=MAX_VESTING_TRANCHES as i64 {
+            reward.credit(100, 100, time).unwrap();
+        }
+        assert!(reward.vesting.iter().all(|slot| slot.amount > 0));
+
+        // nothing is lost by merging
+        reward.release(1000);
+        assert_eq!(reward.rewards_owed, 100 * (MAX_VESTING_TRANCHES as u64 + 1));
+        assert!(reward.vesting.iter().all(|slot| slot.amount == 0));
+    }
+
+    #[test]
+    fn rewards_are_held_back_until_min_stake_duration() {
+        let mut incentive = incentive(100, 5000);
+        let mut stake = stake(0);
+
+        let rewards = incentive.apply_min_stake_duration(&mut stake, [100, 10, 0], false, 50);
+        assert_eq!(rewards, [0; MAX_REWARD_TOKENS]);
+        assert_eq!(stake.pending_rewards, [100, 10, 0]);
+
+        let rewards = incentive.apply_min_stake_duration(&mut stake, [50, 5, 0], false, 100);
+        assert_eq!(rewards, [150, 15, 0]);
+        assert_eq!(stake.pending_rewards, [0; MAX_REWARD_TOKENS]);
+    }
+
+    #[test]
+    fn early_unstake_forfeits_penalty_to_incentive() {
+        let mut incentive = incentive(100, 5000);
+        let mut stake = stake(0);
+        stake.pending_rewards = [100, 0, 0];
+
+        let rewards = incentive.apply_min_stake_duration(&mut stake, [100, 0, 0], true, 50);
+        assert_eq!(rewards, [100, 0, 0]);
+        assert_eq!(incentive.rewards[0].total_reward_unclaimed, 100);
+    }
+
+    #[test]
+    fn no_penalty_after_incentive_ends() {
+        let mut incentive = incentive(100, 5000);
+        let mut stake = stake(950);
+
+        let rewards = incentive.apply_min_stake_duration(&mut stake, [100, 0, 0], true, 1000);
+        assert_eq!(rewards, [100, 0, 0]);
+        assert_eq!(incentive.rewards[0].total_reward_unclaimed, 0);
+    }
 }
@@ -7,6 +7,7 @@ export type IncentiveData = Accounts['incentive'];
 export type DepositData = Accounts['deposit'];
 export type StakeData = Accounts['stake'];
 export type RewardData = Accounts['reward'];
+export type ConfigData = Accounts['config'];
 
 export type CykuraStakerTypes = AnchorTypes<
     CykuraStaker,
@@ -15,9 +16,13 @@ export type CykuraStakerTypes = AnchorTypes<
         deposit: DepositData;
         stake: StakeData;
         reward: RewardData;
+        config: ConfigData;
     }
 >;
 
 type Accounts = CykuraStakerTypes['Accounts'];
 
 export type CykuraStakerProgram = CykuraStakerTypes['Program'];
+
+export type BoostDenominator =
+    CykuraStakerTypes['Defined']['BoostDenominator'];
@@ -1,6 +1,11 @@
 import { BN } from '@project-serum/anchor';
 import { assert } from 'chai';
-import { computeRewardAmountBoosted } from './rewardMath';
+import {
+    computeBoostPercent,
+    computeEffectiveLiquidity,
+    computeEmissions,
+    computeRewardAmountBoosted,
+} from './rewardMath';
 
 describe('rewardMath', () => {
     it('computeRewardAmountBoosted', () => {
@@ -12,11 +17,65 @@ describe('rewardMath', () => {
             liquidity: new BN(100505830),
             secondsPerLiquidityInsideInitialX32: new BN(256),
             secondsPerLiquidityInsideX32: new BN(299),
+            snapshotTime: new BN(1650101377),
             currentTime: new BN(1650101382),
             totalPoolLiquidity: new BN(100505830),
             votingPower: new BN(634),
             totalVotingPower: new BN(100000),
+            boostBasePercent: 40,
         });
         assert(amt.reward.eqn(50790));
     });
+
+    it('computeEffectiveLiquidity', () => {
+        const liquidity = new BN(1000);
+        const poolLiquidity = new BN(10000);
+
+        // without voting power, only the base percentage earns rewards
+        assert(
+            computeEffectiveLiquidity(
+                liquidity,
+                poolLiquidity,
+                new BN(0),
+                new BN(100),
+                25
+            ).eqn(250)
+        );
+        // the boost is capped at the stake liquidity
+        assert(
+            computeEffectiveLiquidity(
+                liquidity,
+                poolLiquidity,
+                new BN(50),
+                new BN(100),
+                25
+            ).eqn(1000)
+        );
+        assert.equal(
+            computeBoostPercent(
+                liquidity,
+                poolLiquidity,
+                new BN(50),
+                new BN(100),
+                25
+            ),
+            400
+        );
+    });
+
+    it('computeEmissions', () => {
+        // 2x for the first 10 seconds, then decaying linearly from 1x to 0 over 10 seconds
+        const schedule = [
+            { time: new BN(0), rateBps: 20_000 },
+            { time: new BN(10), rateBps: 20_000 },
+            { time: new BN(10), rateBps: 10_000 },
+            { time: new BN(20), rateBps: 0 },
+        ];
+
+        assert(computeEmissions([], new BN(100), new BN(160)).eqn(600_000));
+        assert(computeEmissions(schedule, new BN(0), new BN(10)).eqn(200_000));
+        assert(computeEmissions(schedule, new BN(10), new BN(20)).eqn(50_000));
+        assert(computeEmissions(schedule, new BN(5), new BN(15)).eqn(137_500));
+        assert(computeEmissions(schedule, new BN(20), new BN(30)).eqn(0));
+    });
 });
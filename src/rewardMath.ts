@@ -7,136 +7,230 @@ export interface RewardOwed {
     boostPercent?: number;
 }
 
+/** The emission rate of a uniform schedule, in basis points */
+export const UNIFORM_RATE_BPS = 10_000;
+
+export interface EmissionPoint {
+    time: BN;
+    rateBps: number;
+}
+
+/**
+ * Compute the reward owed to a stake. For non-uniform emission schedules, the liquidity-seconds
+ * of the stake are weighted by the average emission rate since its last settlement, which is
+ * exact while the window lies within one segment of the schedule.
+ */
 export function computeRewardAmount({
     totalRewardUnclaimed,
     totalSecondsClaimedX32,
     startTime,
     endTime,
+    emissionSchedule = [],
     liquidity,
     secondsPerLiquidityInsideInitialX32,
     secondsPerLiquidityInsideX32,
+    snapshotTime,
     currentTime,
 }: {
     totalRewardUnclaimed: BN;
     totalSecondsClaimedX32: BN;
     startTime: BN;
     endTime: BN;
+    emissionSchedule?: EmissionPoint[];
     liquidity: BN;
     secondsPerLiquidityInsideInitialX32: BN;
     secondsPerLiquidityInsideX32: BN;
+    snapshotTime: BN;
     currentTime: BN;
 }): RewardOwed {
     // this should never be called before the start time
     invariant(currentTime.gte(startTime));
 
-    const secondsInsideX32 = secondsPerLiquidityInsideX32
-        .sub(secondsPerLiquidityInsideInitialX32)
-        .mul(liquidity);
-
-    const totalSecondsUnlaimedX32 = BN.max(currentTime, endTime)
-        .sub(startTime)
+    const totalSecondsUnclaimedX32 = computeEmissions(
+        emissionSchedule,
+        startTime,
+        BN.max(currentTime, endTime)
+    )
         .shln(32)
+        .divn(UNIFORM_RATE_BPS)
         .sub(totalSecondsClaimedX32);
 
-    const reward = totalRewardUnclaimed
-        .mul(secondsInsideX32)
-        .div(totalSecondsUnlaimedX32);
+    // weigh by the average emission rate over the period, capped by the unclaimed seconds
+    const secondsInsideX32 = currentTime.gt(snapshotTime)
+        ? BN.min(
+              secondsPerLiquidityInsideX32
+                  .sub(secondsPerLiquidityInsideInitialX32)
+                  .mul(liquidity)
+                  .mul(
+                      computeEmissions(
+                          emissionSchedule,
+                          snapshotTime,
+                          currentTime
+                      )
+                  )
+                  .div(currentTime.sub(snapshotTime).muln(UNIFORM_RATE_BPS)),
+              totalSecondsUnclaimedX32
+          )
+        : new BN(0);
+
+    const reward = totalSecondsUnclaimedX32.isZero()
+        ? new BN(0)
+        : totalRewardUnclaimed
+              .mul(secondsInsideX32)
+              .div(totalSecondsUnclaimedX32);
 
     return { reward, secondsInsideX32 };
 }
 
 export function computeRewardAmountBoosted({
-    totalRewardUnclaimed,
-    totalSecondsClaimedX32,
-    startTime,
-    endTime,
-    liquidity,
-    secondsPerLiquidityInsideInitialX32,
-    secondsPerLiquidityInsideX32,
-    currentTime,
     totalPoolLiquidity,
     votingPower,
     totalVotingPower,
-}: {
-    totalRewardUnclaimed: BN;
-    totalSecondsClaimedX32: BN;
-    startTime: BN;
-    endTime: BN;
-    liquidity: BN;
-    secondsPerLiquidityInsideInitialX32: BN;
-    secondsPerLiquidityInsideX32: BN;
-    currentTime: BN;
+    boostBasePercent,
+    liquidity,
+    ...params
+}: Parameters<typeof computeRewardAmount>[0] & {
     totalPoolLiquidity: BN;
     votingPower: BN;
     totalVotingPower: BN;
+    boostBasePercent: number;
 }): RewardOwed {
-    // this should never be called before the start time
-    invariant(currentTime.gte(startTime));
-
-    const effectiveLiquidity = BN.min(
-        liquidity
-            .muln(4)
-            .add(
-                totalPoolLiquidity
-                    .mul(votingPower)
-                    .div(totalVotingPower)
-                    .muln(6)
-            )
-            .divn(10),
-        liquidity
+    const effectiveLiquidity = computeEffectiveLiquidity(
+        liquidity,
+        totalPoolLiquidity,
+        votingPower,
+        totalVotingPower,
+        boostBasePercent
     );
-    const secondsInsideX32 = secondsPerLiquidityInsideX32
-        .sub(secondsPerLiquidityInsideInitialX32)
-        .mul(effectiveLiquidity);
-
-    const totalSecondsUnlaimedX32 = BN.max(endTime, currentTime)
-        .sub(startTime)
-        .shln(32)
-        .sub(totalSecondsClaimedX32);
-
-    const reward = totalRewardUnclaimed
-        .mul(secondsInsideX32)
-        .div(totalSecondsUnlaimedX32);
+    const { reward, secondsInsideX32 } = computeRewardAmount({
+        ...params,
+        liquidity: effectiveLiquidity,
+    });
 
     const boostPercent = computeBoostPercent(
         liquidity,
         totalPoolLiquidity,
         votingPower,
-        totalVotingPower
+        totalVotingPower,
+        boostBasePercent
     );
     return { reward, secondsInsideX32, boostPercent };
 }
 
 /**
- * Compute boost percent for a user's LP position. Max boost is 250%
+ * Compute the liquidity of a stake after applying the boost from voting power, as the program does
+ *
+ * @param liquidity The liquidity in the LP NFT
+ * @param poolLiquidity The total liquidity in the pool
+ * @param votingPower The voting power of the user's wallet
+ * @param totalVotingPower The voting power measured against, given by the incentive's boost denominator
+ * @param boostBasePercent The percentage of liquidity earning rewards regardless of voting power
+ */
+export function computeEffectiveLiquidity(
+    liquidity: BN,
+    poolLiquidity: BN,
+    votingPower: BN,
+    totalVotingPower: BN,
+    boostBasePercent: number
+): BN {
+    const base = liquidity.muln(boostBasePercent).divn(100);
+
+    // the voting power share is capped at the stake liquidity
+    const share = totalVotingPower.isZero()
+        ? new BN(0)
+        : poolLiquidity.mul(votingPower).div(totalVotingPower);
+    const boost = BN.min(share, liquidity)
+        .muln(100 - boostBasePercent)
+        .divn(100);
+
+    return BN.min(base.add(boost), liquidity);
+}
+
+/**
+ * Compute the emissions of a schedule over a time window, in basis point-seconds. The rate is
+ * interpolated linearly between points, and is constant before the first and after the last
+ * point. An empty schedule emits at `UNIFORM_RATE_BPS`.
+ *
+ * @param emissionSchedule The emission points, sorted by time
+ * @param from The beginning of the window in epoch seconds
+ * @param to The end of the window in epoch seconds
+ */
+export function computeEmissions(
+    emissionSchedule: EmissionPoint[],
+    from: BN,
+    to: BN
+): BN {
+    if (emissionSchedule.length === 0) {
+        return overlap(null, null, from, to).muln(UNIFORM_RATE_BPS);
+    }
+    const first = emissionSchedule[0];
+    const last = emissionSchedule[emissionSchedule.length - 1];
+
+    // areas are doubled to keep the trapezoids exact
+    let emissionsX2 = overlap(null, first.time, from, to)
+        .muln(2 * first.rateBps)
+        .add(overlap(last.time, null, from, to).muln(2 * last.rateBps));
+
+    for (let i = 0; i + 1 < emissionSchedule.length; i++) {
+        const a = emissionSchedule[i];
+        const b = emissionSchedule[i + 1];
+        const segmentFrom = BN.max(a.time, from);
+        const segmentTo = BN.min(b.time, to);
+        if (segmentTo.lte(segmentFrom)) {
+            continue;
+        }
+
+        const rateAt = (time: BN) =>
+            new BN(b.rateBps - a.rateBps)
+                .mul(time.sub(a.time))
+                .div(b.time.sub(a.time))
+                .addn(a.rateBps);
+        emissionsX2 = emissionsX2.add(
+            rateAt(segmentFrom)
+                .add(rateAt(segmentTo))
+                .mul(segmentTo.sub(segmentFrom))
+        );
+    }
+
+    return emissionsX2.divn(2);
+}
+
+/** The length of the overlap of `[a, b]` and `[from, to]`, where null bounds are unbounded */
+function overlap(a: BN | null, b: BN | null, from: BN, to: BN): BN {
+    const start = a ? BN.max(a, from) : from;
+    const end = b ? BN.min(b, to) : to;
+    return end.gt(start) ? end.sub(start) : new BN(0);
+}
+
+/**
+ * Compute boost percent for a user's LP position, relative to the liquidity earning rewards
+ * without voting power. Max boost is `10000 / boostBasePercent` percent, 250% for a base of 40%.
  *
  * @param liquidity The liquidity in the LP NFT
  * @param poolLiquidity The total liquidity in the pool
  * @param votingPower The voting power of the user's wallet
- * @param totalVotingPower Total voting power inside the boosting locker
+ * @param totalVotingPower The voting power measured against, given by the incentive's boost denominator
+ * @param boostBasePercent The percentage of liquidity earning rewards regardless of voting power
  * @returns
  */
 export function computeBoostPercent(
     liquidity: BN,
     poolLiquidity: BN,
     votingPower: BN,
-    totalVotingPower: BN
+    totalVotingPower: BN,
+    boostBasePercent: number
 ): number {
-    const baseLiquidity = liquidity.muln(4).divn(10);
-    const effectiveLiquidity = BN.min(
-        baseLiquidity.add(
-            poolLiquidity
-                .mul(votingPower)
-                .div(totalVotingPower)
-                .muln(6)
-                .divn(10)
-        ),
-        liquidity
+    const baseLiquidity = liquidity.muln(boostBasePercent).divn(100);
+    if (baseLiquidity.isZero()) {
+        return 100;
+    }
+    const effectiveLiquidity = computeEffectiveLiquidity(
+        liquidity,
+        poolLiquidity,
+        votingPower,
+        totalVotingPower,
+        boostBasePercent
     );
 
-    const boostPercent = effectiveLiquidity
-        .muln(100)
-        .div(baseLiquidity)
-        .toNumber();
-    return boostPercent;
+    return effectiveLiquidity.muln(100).div(baseLiquidity).toNumber();
 }
@@ -7,23 +7,28 @@ import {
     TransactionEnvelope,
 } from '@saberhq/solana-contrib';
 import {
+    getATAAddressSync,
     getOrCreateATA,
     getTokenAccount,
     TOKEN_PROGRAM_ID,
 } from '@saberhq/token-utils';
-import { PublicKey, Signer } from '@solana/web3.js';
-import { SystemProgram } from '@solana/web3.js';
+import { AccountMeta, PublicKey, Signer } from '@solana/web3.js';
+import { SystemProgram, SYSVAR_RENT_PUBKEY } from '@solana/web3.js';
 import {
+    BITMAP_SEED,
     CyclosCore,
     FACTORY_ADDRESS,
+    FeeAmount,
     IDL as CYCLOS_CORE_IDL,
     OBSERVATION_SEED,
     POOL_SEED,
     POSITION_SEED,
     TICK_SEED,
+    TICK_SPACINGS,
     u16ToSeed,
     u32ToSeed,
 } from '@cykura/sdk';
+import { findEscrowAddress } from '@tribecahq/tribeca-sdk';
 
 import {
     CykuraStakerPrograms,
@@ -32,21 +37,36 @@ import {
 } from './constants';
 import {
     DepositWrapper,
+    findAllowedMintAddress,
+    findAllowedPoolAddress,
+    findConfigAddress,
     findDepositAddress,
     findIncentiveAddress,
     findRewardAddress,
     findStakeAddress,
     findStakeManagerAddress,
+    findVaultAddress,
     IncentiveWrapper,
     RewardWrapper,
 } from './wrappers';
 import {
     PendingDeposit,
+    PendingDepositAndStakes,
     PendingIncentive,
     PendingReward,
+    PendingRewardPayout,
     PendingStake,
+    PendingStakes,
+    RewardAccount,
 } from './wrappers/types';
 import { StakeWrapper } from './wrappers/stake';
+import { BoostDenominator } from './programs';
+import { EmissionPoint } from './rewardMath';
+
+/** The BPF upgradeable loader, which owns the program data account */
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+    'BPFLoaderUpgradeab1e11111111111111111111111'
+);
 
 /**
  * CykuraStakerSDK.
@@ -88,21 +108,50 @@ export class CykuraStakerSDK {
     }
 
     /**
-     * Returns a wrapper and a transaction to create a liquidity mining incentive
+     * Returns a TX to initialize the program config. Must be signed by the upgrade authority.
+     *
+     * @param admin The address which can update the config
      */
-    async createIncentive({
+    async initializeConfig(
+        admin: PublicKey = this.provider.wallet.publicKey
+    ): Promise<TransactionEnvelope> {
+        const [config] = await findConfigAddress();
+        const [programData] = await PublicKey.findProgramAddress(
+            [CYKURA_STAKER_ADDRESSES.CykuraStaker.toBuffer()],
+            BPF_LOADER_UPGRADEABLE_ID
+        );
+
+        return new TransactionEnvelope(this.provider, [
+            await this.programs.CykuraStaker.methods
+                .initializeConfig(admin)
+                .accounts({
+                    config,
+                    programData,
+                    upgradeAuthority: this.provider.wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .instruction(),
+        ]);
+    }
+
+    /**
+     * Returns the accounts shared by both incentive creation instructions
+     */
+    async incentiveAccounts({
         rewardToken,
         pool,
         startTime,
         endTime,
-        refundee = this.provider.wallet.publicKey,
+        refundee,
+        authority,
     }: {
         rewardToken: PublicKey;
         pool: PublicKey;
         startTime: BN;
         endTime: BN;
         refundee: PublicKey;
-    }): Promise<PendingIncentive> {
+        authority: PublicKey;
+    }) {
         const [incentive] = await findIncentiveAddress(
             rewardToken,
             pool,
@@ -110,21 +159,179 @@ export class CykuraStakerSDK {
             startTime,
             endTime
         );
-        const wrapper = new IncentiveWrapper(this, incentive);
+        const [vault] = await findVaultAddress(incentive, rewardToken);
+        const [stakeManager] = await findStakeManagerAddress();
+        const [config] = await findConfigAddress();
+        const [allowedPool] = await findAllowedPoolAddress(pool);
+        const [allowedRewardToken] = await findAllowedMintAddress(rewardToken);
 
         return {
-            wrapper,
+            incentive,
+            rewardToken,
+            vault,
+            stakeManager,
+            pool,
+            refundee,
+            authority,
+            config,
+            allowedPool,
+            allowedRewardToken,
+            payer: this.provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+        };
+    }
+
+    /**
+     * Returns the Cykura core accounts of the position of an LP token
+     *
+     * @param mint Mint address of the LP token
+     */
+    async positionAccounts(mint: PublicKey) {
+        // @ts-ignore
+        const cyclosCore = new anchor.Program<CyclosCore>(
+            CYCLOS_CORE_IDL,
+            FACTORY_ADDRESS,
+            makeAnchorProvider(this.provider)
+        );
+        const [tokenizedPosition] = await PublicKey.findProgramAddress(
+            [POSITION_SEED, mint.toBuffer()],
+            FACTORY_ADDRESS
+        );
+        const { poolId, tickLower, tickUpper } =
+            await cyclosCore.account.tokenizedPositionState.fetch(
+                tokenizedPosition
+            );
+        const {
+            token0,
+            token1,
+            fee,
+            observationIndex,
+            observationCardinalityNext,
+        } = await cyclosCore.account.poolState.fetch(poolId);
+
+        const poolSeeds = [
+            token0.toBuffer(),
+            token1.toBuffer(),
+            u32ToSeed(fee),
+        ];
+        const tickSpacing = TICK_SPACINGS[fee as FeeAmount];
+        const [factoryState] = await PublicKey.findProgramAddress(
+            [],
+            FACTORY_ADDRESS
+        );
+        const [corePosition] = await PublicKey.findProgramAddress(
+            [
+                POSITION_SEED,
+                ...poolSeeds,
+                factoryState.toBuffer(),
+                u32ToSeed(tickLower),
+                u32ToSeed(tickUpper),
+            ],
+            FACTORY_ADDRESS
+        );
+        const [tickLowerState] = await PublicKey.findProgramAddress(
+            [TICK_SEED, ...poolSeeds, u32ToSeed(tickLower)],
+            FACTORY_ADDRESS
+        );
+        const [tickUpperState] = await PublicKey.findProgramAddress(
+            [TICK_SEED, ...poolSeeds, u32ToSeed(tickUpper)],
+            FACTORY_ADDRESS
+        );
+        const [bitmapLower] = await PublicKey.findProgramAddress(
+            [
+                BITMAP_SEED,
+                ...poolSeeds,
+                u16ToSeed(Math.floor(tickLower / tickSpacing) >> 8),
+            ],
+            FACTORY_ADDRESS
+        );
+        const [bitmapUpper] = await PublicKey.findProgramAddress(
+            [
+                BITMAP_SEED,
+                ...poolSeeds,
+                u16ToSeed(Math.floor(tickUpper / tickSpacing) >> 8),
+            ],
+            FACTORY_ADDRESS
+        );
+        const [latestObservation] = await PublicKey.findProgramAddress(
+            [OBSERVATION_SEED, ...poolSeeds, u16ToSeed(observationIndex)],
+            FACTORY_ADDRESS
+        );
+        const [nextObservation] = await PublicKey.findProgramAddress(
+            [
+                OBSERVATION_SEED,
+                ...poolSeeds,
+                u16ToSeed((observationIndex + 1) % observationCardinalityNext),
+            ],
+            FACTORY_ADDRESS
+        );
+
+        return {
+            token0,
+            token1,
+            coreProgram: FACTORY_ADDRESS,
+            tokenizedPosition,
+            factoryState,
+            pool: poolId,
+            corePosition,
+            tickLower: tickLowerState,
+            tickUpper: tickUpperState,
+            bitmapLower,
+            bitmapUpper,
+            latestObservation,
+            nextObservation,
+            vault0: getATAAddressSync({ mint: token0, owner: poolId }),
+            vault1: getATAAddressSync({ mint: token1, owner: poolId }),
+        };
+    }
+
+    /**
+     * Returns a wrapper and a transaction to create a liquidity mining incentive
+     *
+     * @param emissionSchedule The emission points of the incentive, which must share one rate. Rewards are emitted uniformly if empty.
+     * @param permissionless Whether to skip the pool and reward token allowlists
+     */
+    async createIncentive({
+        rewardToken,
+        pool,
+        startTime,
+        endTime,
+        refundee = this.provider.wallet.publicKey,
+        authority = this.provider.wallet.publicKey,
+        emissionSchedule = [],
+        permissionless = false,
+    }: {
+        rewardToken: PublicKey;
+        pool: PublicKey;
+        startTime: BN;
+        endTime: BN;
+        refundee?: PublicKey;
+        authority?: PublicKey;
+        emissionSchedule?: EmissionPoint[];
+        permissionless?: boolean;
+    }): Promise<PendingIncentive> {
+        const accounts = await this.incentiveAccounts({
+            rewardToken,
+            pool,
+            startTime,
+            endTime,
+            refundee,
+            authority,
+        });
+
+        return {
+            wrapper: new IncentiveWrapper(this, accounts.incentive),
             tx: new TransactionEnvelope(this.provider, [
                 await this.programs.CykuraStaker.methods
-                    .createIncentive(startTime, endTime)
-                    .accounts({
-                        incentive,
-                        rewardToken,
-                        pool,
-                        refundee,
-                        payer: this.provider.wallet.publicKey,
-                        systemProgram: SystemProgram.programId,
-                    })
+                    .createIncentive(
+                        startTime,
+                        endTime,
+                        emissionSchedule,
+                        permissionless
+                    )
+                    .accounts(accounts)
                     .instruction(),
             ]),
         };
@@ -132,6 +339,11 @@ export class CykuraStakerSDK {
 
     /**
      * Returns a wrapper and a transaction to create a boosted liquidity mining incentive
+     *
+     * @param boostBasePercent The percentage of liquidity earning rewards regardless of voting power
+     * @param boostDenominator The total voting power the voting power of a staker is measured against
+     * @param emissionSchedule The emission points of the incentive, which must share one rate. Rewards are emitted uniformly if empty.
+     * @param permissionless Whether to skip the pool and reward token allowlists
      */
     async createIncentiveBoosted({
         rewardToken,
@@ -140,36 +352,46 @@ export class CykuraStakerSDK {
         endTime,
         locker,
         refundee = this.provider.wallet.publicKey,
+        authority = this.provider.wallet.publicKey,
+        boostBasePercent = 40,
+        boostDenominator = { maxVotingPower: {} },
+        emissionSchedule = [],
+        permissionless = false,
     }: {
         rewardToken: PublicKey;
         pool: PublicKey;
         startTime: BN;
         endTime: BN;
         locker: PublicKey;
-        refundee: PublicKey;
+        refundee?: PublicKey;
+        authority?: PublicKey;
+        boostBasePercent?: number;
+        boostDenominator?: BoostDenominator;
+        emissionSchedule?: EmissionPoint[];
+        permissionless?: boolean;
     }): Promise<PendingIncentive> {
-        const [incentive] = await findIncentiveAddress(
+        const accounts = await this.incentiveAccounts({
             rewardToken,
             pool,
-            refundee,
             startTime,
-            endTime
-        );
-        const wrapper = new IncentiveWrapper(this, incentive);
+            endTime,
+            refundee,
+            authority,
+        });
+
         return {
-            wrapper,
+            wrapper: new IncentiveWrapper(this, accounts.incentive),
             tx: new TransactionEnvelope(this.provider, [
                 await this.programs.CykuraStaker.methods
-                    .createIncentiveBoosted(startTime, endTime)
-                    .accounts({
-                        incentive,
-                        rewardToken,
-                        pool,
-                        refundee,
-                        payer: this.provider.wallet.publicKey,
-                        systemProgram: SystemProgram.programId,
-                        locker,
-                    })
+                    .createIncentiveBoosted(
+                        startTime,
+                        endTime,
+                        boostBasePercent,
+                        boostDenominator,
+                        emissionSchedule,
+                        permissionless
+                    )
+                    .accounts({ ...accounts, locker })
                     .instruction(),
             ]),
         };
@@ -227,7 +449,8 @@ export class CykuraStakerSDK {
     }
 
     /**
-     * Returns a TX to stake an LP token
+     * Returns a TX to stake an LP token. Boosted incentives are staked with the
+     * vote escrow of the wallet.
      * TODO use token account instead of mint
      *
      * @param mint Mint address of the LP token
@@ -244,6 +467,11 @@ export class CykuraStakerSDK {
             FACTORY_ADDRESS
         );
         const [stake] = await findStakeAddress(mint, incentive);
+        const [config] = await findConfigAddress();
+        const { boostLocker } =
+            await this.programs.CykuraStaker.account.incentive.fetch(
+                incentive
+            );
 
         // @ts-ignore
         const cyclosCore = new anchor.Program<CyclosCore>(
@@ -291,23 +519,42 @@ export class CykuraStakerSDK {
             FACTORY_ADDRESS
         );
 
+        const accounts = {
+            stake,
+            incentive,
+            config,
+            deposit,
+            tokenizedPosition,
+            pool: poolId,
+            tickLower: tickLowerState,
+            tickUpper: tickUpperState,
+            latestObservation,
+            owner: this.provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+        };
+
+        if (boostLocker) {
+            const [escrow] = await findEscrowAddress(
+                boostLocker,
+                this.provider.wallet.publicKey
+            );
+            return {
+                stake: new StakeWrapper(this, stake),
+                tx: new TransactionEnvelope(this.provider, [
+                    await this.programs.CykuraStaker.methods
+                        .stakeTokenBoosted()
+                        .accounts({ ...accounts, locker: boostLocker, escrow })
+                        .instruction(),
+                ]),
+            };
+        }
+
         return {
             stake: new StakeWrapper(this, stake),
             tx: new TransactionEnvelope(this.provider, [
                 await this.programs.CykuraStaker.methods
                     .stakeToken()
-                    .accounts({
-                        stake,
-                        incentive: incentive,
-                        deposit,
-                        tokenizedPosition,
-                        pool: poolId,
-                        tickLower: tickLowerState,
-                        tickUpper: tickUpperState,
-                        latestObservation,
-                        owner: this.provider.wallet.publicKey,
-                        systemProgram: SystemProgram.programId,
-                    })
+                    .accounts(accounts)
                     .instruction(),
             ]),
         };
@@ -335,14 +582,245 @@ export class CykuraStakerSDK {
         return { deposit, stake, tx };
     }
 
+    /**
+     * Returns a TX to stake an LP token in several unboosted incentives of its
+     * pool
+     *
+     * @param mint Mint address of the LP token
+     * @param incentives Incentive addresses
+     */
+    async stakeTokenMulti(
+        mint: PublicKey,
+        incentives: PublicKey[]
+    ): Promise<PendingStakes> {
+        const [config] = await findConfigAddress();
+        const [deposit] = await findDepositAddress(mint);
+        const {
+            tokenizedPosition,
+            pool,
+            tickLower,
+            tickUpper,
+            latestObservation,
+        } = await this.positionAccounts(mint);
+        const { stakes, stakeAccounts } = await this.newStakeAccounts(
+            mint,
+            incentives
+        );
+
+        return {
+            stakes,
+            tx: new TransactionEnvelope(this.provider, [
+                await this.programs.CykuraStaker.methods
+                    .stakeTokenMulti()
+                    .accounts({
+                        config,
+                        deposit,
+                        tokenizedPosition,
+                        pool,
+                        tickLower,
+                        tickUpper,
+                        latestObservation,
+                        owner: this.provider.wallet.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .remainingAccounts(stakeAccounts)
+                    .instruction(),
+            ]),
+        };
+    }
+
+    /**
+     * Returns a TX to deposit an LP token and stake it in several unboosted
+     * incentives of its pool in a single instruction
+     *
+     * @param depositorTokenAccount The token account holding the LP token
+     * @param incentives Incentive addresses
+     */
+    async depositAndStakeMulti(
+        depositorTokenAccount: PublicKey,
+        incentives: PublicKey[]
+    ): Promise<PendingDepositAndStakes> {
+        const tx = new TransactionEnvelope(this.provider, []);
+        const { mint } = await getTokenAccount(
+            this.provider,
+            depositorTokenAccount
+        );
+        const [config] = await findConfigAddress();
+        const [deposit] = await findDepositAddress(mint);
+        const [stakeManager] = await findStakeManagerAddress();
+
+        const { address: depositVault, instruction: createVaultIx } =
+            await getOrCreateATA({
+                provider: this.provider,
+                mint,
+                owner: stakeManager,
+            });
+        if (createVaultIx) {
+            tx.append(createVaultIx);
+        }
+
+        const {
+            tokenizedPosition,
+            pool,
+            tickLower,
+            tickUpper,
+            latestObservation,
+        } = await this.positionAccounts(mint);
+        const { stakes, stakeAccounts } = await this.newStakeAccounts(
+            mint,
+            incentives
+        );
+
+        tx.append(
+            await this.programs.CykuraStaker.methods
+                .depositAndStake()
+                .accounts({
+                    createDeposit: {
+                        deposit,
+                        depositorTokenAccount,
+                        depositVault,
+                        tokenizedPosition,
+                        depositor: this.provider.wallet.publicKey,
+                        systemProgram: SystemProgram.programId,
+                        tokenProgram: TOKEN_PROGRAM_ID,
+                    },
+                    config,
+                    pool,
+                    tickLower,
+                    tickUpper,
+                    latestObservation,
+                })
+                .remainingAccounts(stakeAccounts)
+                .instruction()
+        );
+
+        return {
+            deposit: new DepositWrapper(this, deposit),
+            stakes,
+            tx,
+        };
+    }
+
+    /**
+     * Returns the remaining accounts to create stakes of an LP token: every
+     * incentive followed by the stake of the token in it
+     */
+    async newStakeAccounts(mint: PublicKey, incentives: PublicKey[]) {
+        const stakes: StakeWrapper[] = [];
+        const stakeAccounts: AccountMeta[] = [];
+        for (const incentive of incentives) {
+            const [stake] = await findStakeAddress(mint, incentive);
+            stakes.push(new StakeWrapper(this, stake));
+            stakeAccounts.push(
+                { pubkey: incentive, isSigner: false, isWritable: true },
+                { pubkey: stake, isSigner: false, isWritable: true }
+            );
+        }
+
+        return { stakes, stakeAccounts };
+    }
+
+    /**
+     * Returns the remaining accounts to pay out reward accounts of the wallet:
+     * every reward account followed by the vault of its reward token, the token
+     * account of the wallet receiving the reward and the token account of the
+     * treasury. Missing token accounts of the wallet are created.
+     *
+     * @param rewards The reward accounts to pay out
+     */
+    async rewardPayoutAccounts(
+        rewards: RewardAccount[]
+    ): Promise<PendingRewardPayout> {
+        const tx = new TransactionEnvelope(this.provider, []);
+        const [config] = await findConfigAddress();
+        const { treasury } =
+            await this.programs.CykuraStaker.account.config.fetch(config);
+
+        const payoutAccounts: AccountMeta[] = [];
+        const createdAccounts = new Set<string>();
+        for (const { reward, incentive, rewardToken } of rewards) {
+            const [vault] = await findVaultAddress(incentive, rewardToken);
+            const { address: to, instruction: createToAccountIx } =
+                await getOrCreateATA({
+                    provider: this.provider,
+                    mint: rewardToken,
+                });
+            if (createToAccountIx && !createdAccounts.has(to.toBase58())) {
+                createdAccounts.add(to.toBase58());
+                tx.append(createToAccountIx);
+            }
+            const treasuryTokenAccount = getATAAddressSync({
+                mint: rewardToken,
+                owner: treasury,
+            });
+
+            payoutAccounts.push(
+                ...[reward, vault, to, treasuryTokenAccount].map((pubkey) => ({
+                    pubkey,
+                    isSigner: false,
+                    isWritable: true,
+                }))
+            );
+        }
+
+        return { payoutAccounts, tx };
+    }
+
+    /**
+     * Returns a TX to claim all rewards owed by several reward accounts of the
+     * wallet to its ATAs
+     *
+     * @param rewards The reward accounts to claim from
+     */
+    async claimRewardsMulti(
+        rewards: PublicKey[]
+    ): Promise<TransactionEnvelope> {
+        const [config] = await findConfigAddress();
+        const [stakeManager] = await findStakeManagerAddress();
+
+        const rewardAccounts: RewardAccount[] = [];
+        for (const reward of rewards) {
+            const { incentive, rewardToken } =
+                await this.programs.CykuraStaker.account.reward.fetch(reward);
+            rewardAccounts.push({ reward, incentive, rewardToken });
+        }
+        const { payoutAccounts, tx } = await this.rewardPayoutAccounts(
+            rewardAccounts
+        );
+
+        tx.append(
+            await this.programs.CykuraStaker.methods
+                .claimRewardsMulti()
+                .accounts({
+                    owner: this.provider.wallet.publicKey,
+                    config,
+                    stakeManager,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .remainingAccounts(payoutAccounts)
+                .instruction()
+        );
+
+        return tx;
+    }
+
     /**
      * Returns a TX to create a reward account
+     *
+     * @param incentive The incentive paying out the reward
+     * @param rewardToken A reward token of the incentive
+     * @param rewardOwner The address whose reward is tracked
      */
     async createRewardAccount(
+        incentive: PublicKey,
         rewardToken: PublicKey,
         rewardOwner: PublicKey = this.provider.wallet.publicKey
     ): Promise<PendingReward> {
-        const [reward] = await findRewardAddress(rewardToken, rewardOwner);
+        const [reward] = await findRewardAddress(
+            incentive,
+            rewardToken,
+            rewardOwner
+        );
 
         return {
             reward: new RewardWrapper(this, reward),
@@ -351,6 +829,7 @@ export class CykuraStakerSDK {
                     .createRewardAccount()
                     .accounts({
                         reward,
+                        incentive,
                         rewardToken,
                         rewardOwner,
                         payer: this.provider.wallet.publicKey,
@@ -1,10 +1,16 @@
+import type { BN } from '@project-serum/anchor';
 import { TransactionEnvelope } from '@saberhq/solana-contrib';
 import { getATAAddressSync, getOrCreateATA } from '@saberhq/token-utils';
 import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
-import type { PublicKey } from '@solana/web3.js';
+import { AccountMeta, PublicKey } from '@solana/web3.js';
 import { DepositData } from '../programs';
 import { CykuraStakerSDK } from '../sdk';
-import { findStakeManagerAddress } from './pda';
+import {
+    findConfigAddress,
+    findRewardAddress,
+    findStakeManagerAddress,
+} from './pda';
+import { PendingStakeAccounts, RewardAccount } from './types';
 
 export class DepositWrapper {
     private _deposit: DepositData | null = null;
@@ -70,6 +76,306 @@ export class DepositWrapper {
         return tx;
     }
 
+    /**
+     * Returns the remaining accounts of every stake of the deposit: the stake,
+     * its incentive and the reward accounts of the deposit owner for every
+     * reward token of the incentive. Missing reward accounts are created.
+     */
+    async stakeAccounts(): Promise<PendingStakeAccounts> {
+        const tx = new TransactionEnvelope(this.provider, []);
+        const { mint, owner } = await this.data();
+
+        // the mint follows the discriminator and the bump of a stake
+        const stakes = await this.program.account.stake.all([
+            { memcmp: { offset: 9, bytes: mint.toBase58() } },
+        ]);
+
+        const stakeAccounts: AccountMeta[][] = [];
+        const rewards: RewardAccount[] = [];
+        for (const {
+            publicKey: stake,
+            account: { incentive },
+        } of stakes) {
+            const { rewards: incentiveRewards } =
+                await this.program.account.incentive.fetch(incentive);
+
+            const keys = [stake, incentive];
+            for (const { mint: rewardToken } of incentiveRewards) {
+                if (rewardToken.equals(PublicKey.default)) {
+                    continue;
+                }
+                const [reward] = await findRewardAddress(
+                    incentive,
+                    rewardToken,
+                    owner
+                );
+                const rewardData = await this.provider.getAccountInfo(reward);
+                if (!rewardData) {
+                    const { tx: createRewardAccountTx } =
+                        await this.sdk.createRewardAccount(
+                            incentive,
+                            rewardToken,
+                            owner
+                        );
+                    tx.append(...createRewardAccountTx.instructions);
+                }
+                keys.push(reward);
+                rewards.push({ reward, incentive, rewardToken });
+            }
+
+            stakeAccounts.push(
+                keys.map((pubkey) => ({
+                    pubkey,
+                    isSigner: false,
+                    isWritable: true,
+                }))
+            );
+        }
+
+        return { stakeAccounts, rewards, tx };
+    }
+
+    /**
+     * Returns a TX to unstake the deposit from every incentive it is staked in
+     *
+     * @param stakeAccounts The remaining accounts of the stakes. Defaults to
+     * every stake of the deposit.
+     */
+    async unstakeAll(
+        stakeAccounts?: AccountMeta[][]
+    ): Promise<TransactionEnvelope> {
+        const tx = new TransactionEnvelope(this.provider, []);
+        if (!stakeAccounts) {
+            const pending = await this.stakeAccounts();
+            stakeAccounts = pending.stakeAccounts;
+            tx.append(...pending.tx.instructions);
+        }
+
+        tx.append(
+            await this.program.methods
+                .unstakeAll()
+                .accounts(await this.unstakeAllAccounts())
+                .remainingAccounts(stakeAccounts.flat())
+                .instruction()
+        );
+
+        return tx;
+    }
+
+    /**
+     * Returns a TX to unstake the deposit from every incentive, claim the
+     * rewards of the owner and withdraw the token
+     *
+     * @param to The token account to receive the LP token. If the field is not
+     * provided, the provider wallet's ATA is used and a create ATA instruction
+     * appended.
+     */
+    async exit(to?: PublicKey): Promise<TransactionEnvelope> {
+        const [stakeManager] = await findStakeManagerAddress();
+        const [config] = await findConfigAddress();
+        const { mint } = await this.data();
+        const depositVault = getATAAddressSync({ mint, owner: stakeManager });
+
+        const { stakeAccounts, rewards, tx } = await this.stakeAccounts();
+        const { payoutAccounts, tx: createPayoutAccountsTx } =
+            await this.sdk.rewardPayoutAccounts(rewards);
+        tx.append(...createPayoutAccountsTx.instructions);
+
+        if (!to) {
+            const { address: _to, instruction: createToAccountIx } =
+                await getOrCreateATA({
+                    provider: this.provider,
+                    mint,
+                });
+            to = _to;
+            if (createToAccountIx) {
+                tx.append(createToAccountIx);
+            }
+        }
+
+        tx.append(
+            await this.program.methods
+                .exit()
+                .accounts({
+                    unstakeAll: await this.unstakeAllAccounts(),
+                    depositVault,
+                    stakeManager,
+                    to,
+                    config,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .remainingAccounts([...stakeAccounts.flat(), ...payoutAccounts])
+                .instruction()
+        );
+
+        return tx;
+    }
+
+    /**
+     * Returns a TX to collect the swap fees of the deposited position to the
+     * wallet's ATAs, while the deposit stays staked
+     *
+     * @param amount0Max The maximum amount of token0 to collect
+     * @param amount1Max The maximum amount of token1 to collect
+     */
+    async collectFees(
+        amount0Max: BN,
+        amount1Max: BN
+    ): Promise<TransactionEnvelope> {
+        const tx = new TransactionEnvelope(this.provider, []);
+        const [stakeManager] = await findStakeManagerAddress();
+        const { mint } = await this.data();
+        const { token0, token1, ...coreAccounts } =
+            await this.sdk.positionAccounts(mint);
+
+        const { address: recipientWallet0, instruction: createAccount0Ix } =
+            await getOrCreateATA({ provider: this.provider, mint: token0 });
+        const { address: recipientWallet1, instruction: createAccount1Ix } =
+            await getOrCreateATA({ provider: this.provider, mint: token1 });
+        for (const ix of [createAccount0Ix, createAccount1Ix]) {
+            if (ix) {
+                tx.append(ix);
+            }
+        }
+
+        tx.append(
+            await this.program.methods
+                .collectFees(amount0Max, amount1Max)
+                .accounts({
+                    ...coreAccounts,
+                    deposit: this.depositKey,
+                    owner: this.provider.wallet.publicKey,
+                    depositVault: getATAAddressSync({
+                        mint,
+                        owner: stakeManager,
+                    }),
+                    stakeManager,
+                    recipientWallet0,
+                    recipientWallet1,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .instruction()
+        );
+
+        return tx;
+    }
+
+    /**
+     * Returns a TX to add liquidity from the wallet's ATAs to the deposited
+     * position, settling the rewards of every stake of the deposit
+     */
+    async increaseLiquidity({
+        amount0Desired,
+        amount1Desired,
+        amount0Minimum,
+        amount1Minimum,
+        deadline,
+    }: {
+        amount0Desired: BN;
+        amount1Desired: BN;
+        amount0Minimum: BN;
+        amount1Minimum: BN;
+        deadline: BN;
+    }): Promise<TransactionEnvelope> {
+        const { mint } = await this.data();
+        const { token0, token1, ...coreAccounts } =
+            await this.sdk.positionAccounts(mint);
+        const { stakeAccounts, tx } = await this.stakeAccounts();
+
+        tx.append(
+            await this.program.methods
+                .increaseLiquidity(
+                    amount0Desired,
+                    amount1Desired,
+                    amount0Minimum,
+                    amount1Minimum,
+                    deadline
+                )
+                .accounts({
+                    ...coreAccounts,
+                    deposit: this.depositKey,
+                    owner: this.provider.wallet.publicKey,
+                    tokenAccount0: getATAAddressSync({
+                        mint: token0,
+                        owner: this.provider.wallet.publicKey,
+                    }),
+                    tokenAccount1: getATAAddressSync({
+                        mint: token1,
+                        owner: this.provider.wallet.publicKey,
+                    }),
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .remainingAccounts(stakeAccounts.flat())
+                .instruction()
+        );
+
+        return tx;
+    }
+
+    /**
+     * Returns a TX to remove liquidity from the deposited position, settling
+     * the rewards of every stake of the deposit. The removed tokens are
+     * collected with `collectFees`.
+     */
+    async decreaseLiquidity({
+        liquidity,
+        amount0Minimum,
+        amount1Minimum,
+        deadline,
+    }: {
+        liquidity: BN;
+        amount0Minimum: BN;
+        amount1Minimum: BN;
+        deadline: BN;
+    }): Promise<TransactionEnvelope> {
+        const [stakeManager] = await findStakeManagerAddress();
+        const { mint } = await this.data();
+        const coreAccounts = await this.sdk.positionAccounts(mint);
+        const { stakeAccounts, tx } = await this.stakeAccounts();
+
+        tx.append(
+            await this.program.methods
+                .decreaseLiquidity(
+                    liquidity,
+                    amount0Minimum,
+                    amount1Minimum,
+                    deadline
+                )
+                .accounts({
+                    ...coreAccounts,
+                    deposit: this.depositKey,
+                    owner: this.provider.wallet.publicKey,
+                    depositVault: getATAAddressSync({
+                        mint,
+                        owner: stakeManager,
+                    }),
+                    stakeManager,
+                })
+                .remainingAccounts(stakeAccounts.flat())
+                .instruction()
+        );
+
+        return tx;
+    }
+
+    /**
+     * Returns the accounts to unstake the deposit from every incentive
+     */
+    async unstakeAllAccounts() {
+        const { mint } = await this.data();
+        const { pool, tickLower, tickUpper, latestObservation } =
+            await this.sdk.positionAccounts(mint);
+
+        return {
+            deposit: this.depositKey,
+            owner: this.provider.wallet.publicKey,
+            pool,
+            tickLower,
+            tickUpper,
+            latestObservation,
+        };
+    }
+
     async transferDeposit(to: PublicKey): Promise<TransactionEnvelope> {
         const { owner } = await this.data();
 
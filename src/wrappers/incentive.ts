@@ -1,11 +1,16 @@
 import { TransactionEnvelope } from '@saberhq/solana-contrib';
 import { getATAAddressSync, getOrCreateATA } from '@saberhq/token-utils';
 import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
-import type { PublicKey } from '@solana/web3.js';
+import { PublicKey } from '@solana/web3.js';
 import type BN from 'bn.js';
+import invariant from 'tiny-invariant';
 import { IncentiveData } from '../programs';
 import { CykuraStakerSDK } from '../sdk';
-import { findStakeManagerAddress } from './pda';
+import {
+    findConfigAddress,
+    findStakeManagerAddress,
+    findVaultAddress,
+} from './pda';
 
 export class IncentiveWrapper {
     private _incentive: IncentiveData | null = null;
@@ -34,20 +39,24 @@ export class IncentiveWrapper {
         return this._incentive;
     }
 
-    async addReward(reward: BN, payerTokenAccount?: PublicKey) {
+    /**
+     * Returns a transaction to add rewards to the incentive
+     *
+     * @param reward The amount of reward tokens to add
+     * @param rewardToken The reward token to add. Defaults to the first reward token of the incentive.
+     * @param payerTokenAccount The token account paying the reward. Defaults to the wallet's ATA.
+     */
+    async addReward(
+        reward: BN,
+        rewardToken?: PublicKey,
+        payerTokenAccount?: PublicKey
+    ) {
         const tx = new TransactionEnvelope(this.provider, []);
-        const [stakeManager] = await findStakeManagerAddress();
-        const { rewardToken } = await this.data();
-
-        const { address: vault, instruction: createVaultIx } =
-            await getOrCreateATA({
-                provider: this.provider,
-                mint: rewardToken,
-                owner: stakeManager,
-            });
-        if (createVaultIx) {
-            tx.append(createVaultIx);
+        if (!rewardToken) {
+            rewardToken = (await this.data()).rewards[0].mint;
         }
+        const [vault] = await findVaultAddress(this.incentiveKey, rewardToken);
+        const [config] = await findConfigAddress();
 
         if (!payerTokenAccount) {
             payerTokenAccount = await getATAAddressSync({
@@ -61,6 +70,7 @@ export class IncentiveWrapper {
                 .addReward(reward)
                 .accounts({
                     incentive: this.incentiveKey,
+                    config,
                     vault,
                     payer: this.provider.wallet.publicKey,
                     payerTokenAccount,
@@ -73,47 +83,100 @@ export class IncentiveWrapper {
     }
 
     /**
-     * Returns a transaction to end the incentive, transferring leftover balance to the refundee
+     * Returns a transaction to add tokens to the reserve paying the lock bonus of a boosted
+     * incentive
      *
-     * @param refundeeTokenAccount The token account receiving the refund. If `refundeeTokenAccount`
-     * is not provided, the refundee's ATA is used. A create ATA instruction is appended if the
-     * default ATA does not exist.
+     * @param amount The amount of locker tokens to add
+     * @param lockerToken The token of the boost locker. Defaults to the first reward token of the incentive.
+     * @param payerTokenAccount The token account paying the bonus. Defaults to the wallet's ATA.
      */
-    async endIncentive(
-        refundeeTokenAccount?: PublicKey
-    ): Promise<TransactionEnvelope> {
-        const [stakeManager] = await findStakeManagerAddress();
-        const { rewardToken, refundee } = await this.data();
-        const vault = await getATAAddressSync({
-            mint: rewardToken,
-            owner: stakeManager,
-        });
-
-        const tx = new TransactionEnvelope(this.provider, []);
+    async addLockBonus(
+        amount: BN,
+        lockerToken?: PublicKey,
+        payerTokenAccount?: PublicKey
+    ) {
+        const { rewards, boostLocker: locker } = await this.data();
+        invariant(locker, 'incentive is not boosted');
+        if (!lockerToken) {
+            lockerToken = rewards[0].mint;
+        }
+        const [vault] = await findVaultAddress(this.incentiveKey, lockerToken);
+        const [config] = await findConfigAddress();
 
-        if (!refundeeTokenAccount) {
-            const getOrCreateATAResult = await getOrCreateATA({
-                provider: this.provider,
-                mint: rewardToken,
-                owner: refundee,
+        if (!payerTokenAccount) {
+            payerTokenAccount = await getATAAddressSync({
+                mint: lockerToken,
+                owner: this.provider.wallet.publicKey,
             });
-            refundeeTokenAccount = getOrCreateATAResult.address;
+        }
+
+        return new TransactionEnvelope(this.provider, [
+            await this.sdk.programs.CykuraStaker.methods
+                .addLockBonus(amount)
+                .accounts({
+                    incentive: this.incentiveKey,
+                    config,
+                    locker,
+                    vault,
+                    payer: this.provider.wallet.publicKey,
+                    payerTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .instruction(),
+        ]);
+    }
 
-            if (getOrCreateATAResult.instruction) {
-                tx.append(getOrCreateATAResult.instruction);
+    /**
+     * Returns a transaction to end the incentive, transferring leftover balance of every reward
+     * token to the refundee's ATAs. Create ATA instructions are appended for ATAs which do not exist.
+     */
+    async endIncentive(): Promise<TransactionEnvelope> {
+        const [stakeManager] = await findStakeManagerAddress();
+        const { rewards, refundee, payer } = await this.data();
+
+        const tx = new TransactionEnvelope(this.provider, []);
+        const vaults: PublicKey[] = [];
+        const refundeeTokenAccounts: PublicKey[] = [];
+        for (const { mint } of rewards) {
+            if (mint.equals(PublicKey.default)) {
+                continue;
+            }
+            const [vault] = await findVaultAddress(this.incentiveKey, mint);
+            const { address: refundeeTokenAccount, instruction } =
+                await getOrCreateATA({
+                    provider: this.provider,
+                    mint,
+                    owner: refundee,
+                });
+            if (instruction) {
+                tx.append(instruction);
             }
+            vaults.push(vault);
+            refundeeTokenAccounts.push(refundeeTokenAccount);
         }
 
+        // the vault and refundee token account of every other reward token, in pairs
+        const remainingAccounts = vaults.slice(1).flatMap((vault, i) => [
+            { pubkey: vault, isSigner: false, isWritable: true },
+            {
+                pubkey: refundeeTokenAccounts[i + 1],
+                isSigner: false,
+                isWritable: true,
+            },
+        ]);
+
         tx.append(
             await this.sdk.programs.CykuraStaker.methods
                 .endIncentive()
                 .accounts({
                     incentive: this.incentiveKey,
-                    vault,
+                    vault: vaults[0],
                     stakeManager,
-                    refundeeTokenAccount,
+                    refundeeTokenAccount: refundeeTokenAccounts[0],
+                    payer,
                     tokenProgram: TOKEN_PROGRAM_ID,
                 })
+                .remainingAccounts(remainingAccounts)
                 .instruction()
         );
 
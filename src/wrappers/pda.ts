@@ -56,15 +56,56 @@ export const findStakeAddress = async (
 };
 
 export const findRewardAddress = async (
+    incentive: PublicKey,
     rewardToken: PublicKey,
     owner: PublicKey
 ): Promise<[PublicKey, number]> => {
     return PublicKey.findProgramAddress(
         [
             utils.bytes.utf8.encode('Reward'),
+            incentive.toBuffer(),
             rewardToken.toBuffer(),
             owner.toBuffer(),
         ],
         CYKURA_STAKER_ADDRESSES.CykuraStaker
     );
 };
+
+export const findVaultAddress = async (
+    incentive: PublicKey,
+    rewardToken: PublicKey
+): Promise<[PublicKey, number]> => {
+    return PublicKey.findProgramAddress(
+        [
+            utils.bytes.utf8.encode('Vault'),
+            incentive.toBuffer(),
+            rewardToken.toBuffer(),
+        ],
+        CYKURA_STAKER_ADDRESSES.CykuraStaker
+    );
+};
+
+export const findConfigAddress = async (): Promise<[PublicKey, number]> => {
+    return PublicKey.findProgramAddress(
+        [utils.bytes.utf8.encode('Config')],
+        CYKURA_STAKER_ADDRESSES.CykuraStaker
+    );
+};
+
+export const findAllowedPoolAddress = async (
+    pool: PublicKey
+): Promise<[PublicKey, number]> => {
+    return PublicKey.findProgramAddress(
+        [utils.bytes.utf8.encode('AllowedPool'), pool.toBuffer()],
+        CYKURA_STAKER_ADDRESSES.CykuraStaker
+    );
+};
+
+export const findAllowedMintAddress = async (
+    mint: PublicKey
+): Promise<[PublicKey, number]> => {
+    return PublicKey.findProgramAddress(
+        [utils.bytes.utf8.encode('AllowedMint'), mint.toBuffer()],
+        CYKURA_STAKER_ADDRESSES.CykuraStaker
+    );
+};
@@ -3,14 +3,18 @@ import { TransactionEnvelope } from '@saberhq/solana-contrib';
 import {
     getATAAddressSync,
     getOrCreateATA,
-    MAX_U64,
     TOKEN_PROGRAM_ID,
-    u64,
 } from '@saberhq/token-utils';
 import type { PublicKey } from '@solana/web3.js';
+import { findEscrowAddress, TRIBECA_ADDRESSES } from '@tribecahq/tribeca-sdk';
+import invariant from 'tiny-invariant';
 import { RewardData } from '../programs';
 import { CykuraStakerSDK } from '../sdk';
-import { findStakeManagerAddress } from './pda';
+import {
+    findConfigAddress,
+    findStakeManagerAddress,
+    findVaultAddress,
+} from './pda';
 
 export class RewardWrapper {
     private _reward: RewardData | null = null;
@@ -39,22 +43,18 @@ export class RewardWrapper {
     /**
      * Returns a TX to claim accrued reward
      * @param rewardRequested The amount of reward to transfer out. Pass u64::MAX to transfer entire pending amount.
-     * @param rewardToken The reward token. This field is optional if the reward account is created
      * @param to The token account to receive reward. If the field is not provided, the provider wallet's
      * ATA is used and a create ATA instruction appended.
      */
-    async claimReward(
-        rewardRequested: BN,
-        rewardToken?: PublicKey,
-        to?: PublicKey
-    ) {
-        if (!rewardToken) {
-            ({ rewardToken } = await this.data());
-        }
+    async claimReward(rewardRequested: BN, to?: PublicKey) {
+        const { incentive, rewardToken } = await this.data();
         const [stakeManager] = await findStakeManagerAddress();
-        const vault = await getATAAddressSync({
+        const [vault] = await findVaultAddress(incentive, rewardToken);
+        const [config] = await findConfigAddress();
+        const { treasury } = await this.program.account.config.fetch(config);
+        const treasuryTokenAccount = await getATAAddressSync({
             mint: rewardToken,
-            owner: stakeManager,
+            owner: treasury,
         });
 
         const tx = new TransactionEnvelope(this.provider, []);
@@ -71,16 +71,17 @@ export class RewardWrapper {
             }
         }
 
-        console.log('stake manager', stakeManager);
         tx.append(
             await this.program.methods
                 .claimReward(rewardRequested)
                 .accounts({
                     reward: this.rewardKey,
                     owner: this.provider.walletKey,
+                    config,
                     vault,
                     stakeManager,
                     to,
+                    treasuryTokenAccount,
                     tokenProgram: TOKEN_PROGRAM_ID,
                 })
                 .instruction()
@@ -88,4 +89,53 @@ export class RewardWrapper {
 
         return tx;
     }
+    /**
+     * Returns a TX to claim accrued reward into the wallet's escrow in the
+     * boost locker of the incentive, earning the lock bonus of the incentive if
+     * the escrow stays locked for at least 90 days
+     *
+     * @param amountRequested The amount of reward to lock. Pass zero to lock the entire pending amount.
+     * @param lockDuration The new duration of the lock from now, if it should be extended
+     */
+    async claimAndLock(amountRequested: BN, lockDuration: BN | null = null) {
+        const { incentive, rewardToken } = await this.data();
+        const { boostLocker: locker } =
+            await this.program.account.incentive.fetch(incentive);
+        invariant(locker, 'incentive is not boosted');
+
+        const [stakeManager] = await findStakeManagerAddress();
+        const [vault] = await findVaultAddress(incentive, rewardToken);
+        const [config] = await findConfigAddress();
+        const { treasury } = await this.program.account.config.fetch(config);
+        const [escrow] = await findEscrowAddress(
+            locker,
+            this.provider.walletKey
+        );
+
+        return new TransactionEnvelope(this.provider, [
+            await this.program.methods
+                .claimAndLock(amountRequested, lockDuration)
+                .accounts({
+                    reward: this.rewardKey,
+                    owner: this.provider.walletKey,
+                    config,
+                    incentive,
+                    vault,
+                    stakeManager,
+                    treasuryTokenAccount: getATAAddressSync({
+                        mint: rewardToken,
+                        owner: treasury,
+                    }),
+                    locker,
+                    escrow,
+                    escrowTokens: getATAAddressSync({
+                        mint: rewardToken,
+                        owner: escrow,
+                    }),
+                    lockedVoterProgram: TRIBECA_ADDRESSES.LockedVoter,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .instruction(),
+        ]);
+    }
 }
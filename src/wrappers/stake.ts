@@ -16,7 +16,6 @@ import * as anchor from '@project-serum/anchor';
 import { BN } from '@project-serum/anchor';
 import { TransactionEnvelope } from '@saberhq/solana-contrib';
 import { makeAnchorProvider } from '@saberhq/anchor-contrib';
-import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { PublicKey } from '@solana/web3.js';
 import {
     findEscrowAddress,
@@ -63,41 +62,35 @@ export class StakeWrapper {
     }
 
     /**
-     * Returns a TX to unstake from an incentive, boosted or otherwise. The reward account is created
-     * if it does not already exist.
+     * Returns a TX to unstake from an incentive, boosted or otherwise. Reward accounts of the
+     * deposit owner are created for every reward token if they do not already exist.
      *
-     * @param deposit
-     * @returns
+     * @param deposit The deposit of the stake
+     * @returns The reward account of the first reward token, and the TX
      */
     async unstakeToken(deposit: DepositWrapper): Promise<PendingUnstake> {
         const tx = new TransactionEnvelope(this.provider, []);
 
         const { mint: nftMint, incentive } = await this.data();
+        const { owner } = await deposit.data();
         const incentiveWrapper = new IncentiveWrapper(this.sdk, incentive);
-        const { boostLocker, rewardToken: incentiveRewardToken } =
-            await incentiveWrapper.data();
-
-        if (!boostLocker) {
-            console.log(`Could not find boostlocker, fails`);
-            return Promise.resolve({
-                reward: new RewardWrapper(this.sdk, PublicKey.default),
-                tx,
-            });
-        }
-
-        const [reward] = await findRewardAddress(
-            incentiveRewardToken,
-            this.provider.wallet.publicKey
-        );
-        const rewardData = await this.provider.getAccountInfo(reward);
-        if (!rewardData) {
-            const { tx: createRewardAccountTx } =
-                await this.sdk.createRewardAccount(
-                    incentiveRewardToken,
-                    this.provider.wallet.publicKey
-                );
-            tx.append(...createRewardAccountTx.instructions);
+        const { rewards } = await incentiveWrapper.data();
+
+        const rewardKeys: PublicKey[] = [];
+        for (const { mint } of rewards) {
+            if (mint.equals(PublicKey.default)) {
+                continue;
+            }
+            const [reward] = await findRewardAddress(incentive, mint, owner);
+            const rewardData = await this.provider.getAccountInfo(reward);
+            if (!rewardData) {
+                const { tx: createRewardAccountTx } =
+                    await this.sdk.createRewardAccount(incentive, mint, owner);
+                tx.append(...createRewardAccountTx.instructions);
+            }
+            rewardKeys.push(reward);
         }
+        const [reward, ...otherRewards] = rewardKeys;
 
         // @ts-ignore
         const cyclosCore = new anchor.Program<CyclosCore>(
@@ -150,48 +143,29 @@ export class StakeWrapper {
             FACTORY_ADDRESS
         );
 
-        if (incentive) {
-            const [escrow] = await findEscrowAddress(
-                boostLocker,
-                this.provider.walletKey
-            );
-
-            tx.append(
-                await this.sdk.programs.CykuraStaker.methods
-                    .unstakeTokenBoosted()
-                    .accounts({
-                        stake: this.stakeKey,
-                        incentive,
-                        locker: boostLocker,
-                        escrow,
-                        deposit: deposit.depositKey,
-                        reward,
-                        pool: poolId,
-                        tickLower: tickLowerState,
-                        tickUpper: tickUpperState,
-                        latestObservation,
-                        signer: this.provider.walletKey,
-                    })
-                    .instruction()
-            );
-        } else {
-            tx.append(
-                await this.sdk.programs.CykuraStaker.methods
-                    .unstakeToken()
-                    .accounts({
-                        stake: this.stakeKey,
-                        incentive,
-                        deposit: deposit.depositKey,
-                        reward,
-                        pool: poolId,
-                        tickLower: tickLowerState,
-                        tickUpper: tickUpperState,
-                        latestObservation,
-                        tokenProgram: TOKEN_PROGRAM_ID,
-                    })
-                    .instruction()
-            );
-        }
+        tx.append(
+            await this.sdk.programs.CykuraStaker.methods
+                .unstakeToken()
+                .accounts({
+                    stake: this.stakeKey,
+                    incentive,
+                    deposit: deposit.depositKey,
+                    reward,
+                    pool: poolId,
+                    tickLower: tickLowerState,
+                    tickUpper: tickUpperState,
+                    latestObservation,
+                    signer: this.provider.walletKey,
+                })
+                .remainingAccounts(
+                    otherRewards.map((pubkey) => ({
+                        pubkey,
+                        isSigner: false,
+                        isWritable: true,
+                    }))
+                )
+                .instruction()
+        );
 
         return {
             tx,
@@ -208,15 +182,22 @@ export class StakeWrapper {
             incentive,
             liquidity,
             secondsPerLiquidityInsideInitialX32,
+            snapshotTime,
         } = await this.data();
         const incentiveWrapper = new IncentiveWrapper(this.sdk, incentive);
         const {
-            totalRewardUnclaimed,
+            rewards,
             totalSecondsClaimedX32,
             startTime,
             endTime,
+            emissionSchedule,
+            emissionPointCount,
             boostLocker,
+            boostBasePercent,
+            boostDenominator,
         } = await incentiveWrapper.data();
+        const { totalRewardUnclaimed } = rewards[0];
+        const schedule = emissionSchedule.slice(0, emissionPointCount);
 
         const [tokenizedPosition] = await PublicKey.findProgramAddress(
             [POSITION_SEED, mint.toBuffer()],
@@ -307,18 +288,6 @@ export class StakeWrapper {
                     boostLocker
                 );
 
-            // Total voting power
-            const lockerWrapper = new LockerWrapper(
-                tribecaSdk,
-                boostLocker,
-                governor
-            );
-            const { lockedSupply, params: lockerParams } =
-                await lockerWrapper.data();
-            const totalVotingPower = lockedSupply.muln(
-                lockerParams.maxStakeVoteMultiplier
-            );
-
             // Voting power of the user
             const [escrowKey] = await findEscrowAddress(boostLocker, owner);
             const escrowWrapper = new VoteEscrow(
@@ -330,6 +299,19 @@ export class StakeWrapper {
             );
             const votingPower = await escrowWrapper.calculateVotingPower();
 
+            // Total voting power, as given by the boost denominator of the incentive
+            const lockerWrapper = new LockerWrapper(
+                tribecaSdk,
+                boostLocker,
+                governor
+            );
+            const { lockedSupply, params: lockerParams } =
+                await lockerWrapper.data();
+            const totalVotingPower =
+                'maxVotingPower' in boostDenominator
+                    ? lockedSupply.muln(lockerParams.maxStakeVoteMultiplier)
+                    : lockedSupply;
+
             console.log(
                 'locked supply',
                 lockedSupply.toString(),
@@ -343,13 +325,16 @@ export class StakeWrapper {
                 totalSecondsClaimedX32,
                 startTime,
                 endTime,
+                emissionSchedule: schedule,
                 liquidity,
                 secondsPerLiquidityInsideInitialX32,
                 secondsPerLiquidityInsideX32,
+                snapshotTime,
                 currentTime: new BN(time),
                 totalPoolLiquidity,
                 votingPower,
                 totalVotingPower,
+                boostBasePercent,
             });
         } else {
             return computeRewardAmount({
@@ -357,9 +342,11 @@ export class StakeWrapper {
                 totalSecondsClaimedX32,
                 startTime,
                 endTime,
+                emissionSchedule: schedule,
                 liquidity,
                 secondsPerLiquidityInsideInitialX32,
                 secondsPerLiquidityInsideX32,
+                snapshotTime,
                 currentTime: new BN(time),
             });
         }
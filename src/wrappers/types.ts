@@ -1,4 +1,5 @@
 import type { PublicKey, TransactionEnvelope } from '@saberhq/solana-contrib';
+import type { AccountMeta } from '@solana/web3.js';
 import { DepositWrapper } from './deposit';
 
 import type { IncentiveWrapper } from './incentive';
@@ -26,6 +27,11 @@ export type PendingStake = {
     tx: TransactionEnvelope;
 };
 
+export type PendingStakes = {
+    stakes: StakeWrapper[];
+    tx: TransactionEnvelope;
+};
+
 export type PendingUnstake = {
     reward: RewardWrapper;
     tx: TransactionEnvelope;
@@ -36,3 +42,35 @@ export type PendingDepositAndStake = {
     stake: StakeWrapper;
     tx: TransactionEnvelope;
 };
+
+export type PendingDepositAndStakes = {
+    deposit: DepositWrapper;
+    stakes: StakeWrapper[];
+    tx: TransactionEnvelope;
+};
+
+/** A reward account, with the incentive and reward token it tracks */
+export type RewardAccount = {
+    reward: PublicKey;
+    incentive: PublicKey;
+    rewardToken: PublicKey;
+};
+
+/**
+ * The remaining accounts of every stake of a deposit, and a TX creating the
+ * missing reward accounts
+ */
+export type PendingStakeAccounts = {
+    stakeAccounts: AccountMeta[][];
+    rewards: RewardAccount[];
+    tx: TransactionEnvelope;
+};
+
+/**
+ * The remaining accounts to pay out reward accounts, and a TX creating the
+ * missing token accounts receiving the rewards
+ */
+export type PendingRewardPayout = {
+    payoutAccounts: AccountMeta[];
+    tx: TransactionEnvelope;
+};
@@ -1,18 +1,40 @@
 import * as chai from "chai"
 import { web3, BN } from '@project-serum/anchor'
-import { PublicKey } from '@saberhq/solana-contrib'
+import { PublicKey, TransactionEnvelope } from '@saberhq/solana-contrib'
 import { chaiSolana, expectTX } from '@saberhq/chai-solana'
 import { assert } from 'chai'
 import { createMintsAndAirdrop } from "./utils/createMintsAndAirdrop"
 import { setupEscrowAndLockTokens } from "./utils/setupEscrowAndLockTokens"
 import { setupWorkspace } from "./utils/setupWorkspace"
 import { createCyclosPosition, swapExactInput } from "./utils/createCyclosPosition"
-import { DepositWrapper, IncentiveWrapper, RewardWrapper } from "../src"
+import {
+  DepositWrapper,
+  findAllowedMintAddress,
+  findAllowedPoolAddress,
+  findConfigAddress,
+  findRewardAddress,
+  findStakeManagerAddress,
+  findVaultAddress,
+  IncentiveWrapper,
+  RewardWrapper,
+} from "../src"
 import { StakeWrapper } from "../src/wrappers/stake"
-import { sleep } from "@saberhq/token-utils"
+import { getTokenAccount, sleep, TOKEN_PROGRAM_ID } from "@saberhq/token-utils"
 
 chai.use(chaiSolana)
 
+/**
+ * Simulates a transaction and asserts that it fails with the given program error
+ */
+async function expectProgramError(tx: TransactionEnvelope, errorCode: string) {
+  const { value } = await tx.simulate()
+  assert(value.err, `expected ${errorCode}, but the transaction succeeded`)
+  assert(
+    value.logs?.some((log) => log.includes(`Error Code: ${errorCode}`)),
+    `expected ${errorCode}, got logs:\n${value.logs?.join("\n")}`
+  )
+}
+
 /**
  * Integration tests for Cykura staker (liquidity mining)
  *
@@ -23,6 +45,7 @@ describe('cykura-staker', () => {
   const cykuraStakerSdk = setupWorkspace()
   const provider = cykuraStakerSdk.provider
   const owner = provider.wallet.publicKey
+  const program = cykuraStakerSdk.programs.CykuraStaker
 
   // token accounts and ATAs
   let token0: web3.PublicKey
@@ -53,6 +76,14 @@ describe('cykura-staker', () => {
   let rewardWrapper: RewardWrapper
   let stakeWrapper: StakeWrapper
 
+  // Unboosted incentives in token1, to stake the position in several incentives at once
+  let multiIncentiveWrappers: IncentiveWrapper[]
+  let multiStakeWrappers: StakeWrapper[]
+  let multiStakeAccounts: web3.AccountMeta[][]
+
+  // An incentive in token1 and token0 which has not started yet
+  let pendingIncentiveWrapper: IncentiveWrapper
+
   it('create token mints and airdrop to wallet', async () => {
     ({ token0, token1, ata0, ata1 } = await createMintsAndAirdrop(provider))
   })
@@ -64,6 +95,121 @@ describe('cykura-staker', () => {
     ammAccounts = await createCyclosPosition(provider, token0, token1)
   })
 
+  it('initialize the program config', async () => {
+    const initializeConfigTx = await cykuraStakerSdk.initializeConfig()
+    await expectTX(initializeConfigTx, "initialize config").to.be.fulfilled
+  })
+
+  it('timelock protocol fee increases', async () => {
+    const [config] = await findConfigAddress()
+    const setProtocolFee = async (feeBps: number) => new TransactionEnvelope(provider, [
+      await program.methods
+        .setProtocolFee(feeBps, owner)
+        .accounts({ config, admin: owner })
+        .instruction()
+    ])
+
+    await expectProgramError(await setProtocolFee(1001), "ProtocolFeeTooLarge")
+
+    // an increase only takes effect once the timelock expires
+    await expectTX(await setProtocolFee(500), "increase protocol fee").to.be.fulfilled
+    let configData = await program.account.config.fetch(config)
+    assert.equal(configData.feeBps, 0)
+    assert.equal(configData.pendingFeeBps, 500)
+    assert(configData.pendingFeeEffectiveAt.gtn(0))
+
+    // a decrease applies immediately and drops the pending increase
+    await expectTX(await setProtocolFee(0), "decrease protocol fee").to.be.fulfilled
+    configData = await program.account.config.fetch(config)
+    assert.equal(configData.feeBps, 0)
+    assert.isNull(configData.pendingFeeBps)
+  })
+
+  it('reject config limits above their caps', async () => {
+    const [config] = await findConfigAddress()
+    const { maxIncentiveDuration, maxIncentiveStartLeadTime } = await program.account.config.fetch(config)
+    const setConfig = async (duration: BN, leadTime: BN) => new TransactionEnvelope(provider, [
+      await program.methods
+        .setConfig(duration, leadTime, null)
+        .accounts({ config, admin: owner })
+        .instruction()
+    ])
+
+    // 5 years and 1 year
+    const maxIncentiveDurationCap = new BN(157_680_000)
+    const maxIncentiveStartLeadTimeCap = new BN(31_536_000)
+    await expectProgramError(
+      await setConfig(maxIncentiveDurationCap.addn(1), maxIncentiveStartLeadTime),
+      "InvalidConfig"
+    )
+    await expectProgramError(
+      await setConfig(maxIncentiveDuration, maxIncentiveStartLeadTimeCap.addn(1)),
+      "InvalidConfig"
+    )
+    await expectProgramError(
+      await setConfig(new BN(0), maxIncentiveStartLeadTime),
+      "InvalidConfig"
+    )
+
+    await expectTX(
+      await setConfig(maxIncentiveDurationCap, maxIncentiveStartLeadTimeCap),
+      "set config at the caps"
+    ).to.be.fulfilled
+
+    // restore the initial limits
+    await expectTX(
+      await setConfig(maxIncentiveDuration, maxIncentiveStartLeadTime),
+      "set config"
+    ).to.be.fulfilled
+  })
+
+  it('enforce the allowlists while they are enabled', async () => {
+    const [config] = await findConfigAddress()
+    const setAllowlistEnabled = async (enabled: boolean) => new TransactionEnvelope(provider, [
+      await program.methods
+        .setAllowlistEnabled(enabled)
+        .accounts({ config, admin: owner })
+        .instruction()
+    ])
+    await expectTX(await setAllowlistEnabled(true), "enable allowlist").to.be.fulfilled
+
+    const slot = await provider.connection.getSlot()
+    const blockTime = await provider.connection.getBlockTime(slot)
+    const createIncentive = async (permissionless: boolean) => (
+      await cykuraStakerSdk.createIncentive({
+        rewardToken: token1,
+        pool: ammAccounts.poolState,
+        startTime: new BN(blockTime! + 100),
+        endTime: new BN(blockTime! + 200),
+        permissionless,
+      })
+    ).tx
+
+    await expectProgramError(await createIncentive(false), "PoolNotAllowed")
+
+    const [allowedPool] = await findAllowedPoolAddress(ammAccounts.poolState)
+    const allowPoolTx = new TransactionEnvelope(provider, [
+      await program.methods
+        .allowPool()
+        .accounts({
+          allowedPool,
+          pool: ammAccounts.poolState,
+          config,
+          admin: owner,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .instruction()
+    ])
+    await expectTX(allowPoolTx, "allow pool").to.be.fulfilled
+    await expectProgramError(await createIncentive(false), "RewardTokenNotAllowed")
+
+    // permissionless incentives skip the allowlists
+    const { value } = await (await createIncentive(true)).simulate()
+    assert.isNull(value.err)
+
+    await expectTX(await setAllowlistEnabled(false), "disable allowlist").to.be.fulfilled
+  })
+
   it('create a new boosted incentive', async () => {
     const slot = await provider.connection.getSlot()
     const blockTime = await provider.connection.getBlockTime(slot)
@@ -84,7 +230,7 @@ describe('cykura-staker', () => {
     await expectTX(createIncentiveTx, "create incentive").to.be.fulfilled
 
     const incentiveData = await incentiveWrapper.data()
-    assert(incentiveData.rewardToken.equals(token0))
+    assert(incentiveData.rewards[0].mint.equals(token0))
     assert(incentiveData.pool.equals(ammAccounts.poolState))
     assert(incentiveData.refundee.equals(owner))
     assert(incentiveData.startTime.eq(startTime))
@@ -98,7 +244,12 @@ describe('cykura-staker', () => {
     await expectTX(addRewardTx, "add reward").to.be.fulfilled
 
     const incentiveData = await incentiveWrapper.reload()
-    assert(incentiveData.totalRewardUnclaimed.eq(rewardAmount))
+    assert(incentiveData.rewards[0].totalRewardUnclaimed.eq(rewardAmount))
+
+    // rewards are held in a vault owned by the incentive
+    const [vault] = await findVaultAddress(incentiveWrapper.incentiveKey, token0)
+    const { amount } = await getTokenAccount(provider, vault)
+    assert(amount.eq(rewardAmount))
   })
 
   it('deposit and stake NFT', async () => {
@@ -149,9 +300,13 @@ describe('cykura-staker', () => {
     const { rewardsOwed } =  await rewardWrapper.data()
     console.log('reward owed', rewardsOwed.toString())
 
+    // lock half of the reward in the boost escrow
+    const claimAndLockTx = await rewardWrapper.claimAndLock(rewardsOwed.divn(2))
+    await expectTX(claimAndLockTx, "claim and lock reward").to.be.fulfilled
+
     // transfer out reward from reward account to the user
     const u64Max = new BN(1).shln(63) // pass u64::MAX to completely transfer entire pending reward
-    const claimRewardTx = await rewardWrapper.claimReward(u64Max, token0)
+    const claimRewardTx = await rewardWrapper.claimReward(u64Max)
     await expectTX(claimRewardTx, "claim reward").to.be.fulfilled
 
     // Notes-
@@ -165,10 +320,353 @@ describe('cykura-staker', () => {
     await expectTX(withdrawTokenTx, "withdraw token").to.be.fulfilled
   })
 
+  it('create unboosted incentives', async () => {
+    const slot = await provider.connection.getSlot()
+    const blockTime = await provider.connection.getBlockTime(slot)
+
+    multiIncentiveWrappers = []
+    for (const duration of [60, 61]) {
+      const { wrapper, tx: createIncentiveTx } = await cykuraStakerSdk.createIncentive({
+        rewardToken: token1,
+        pool: ammAccounts.poolState,
+        startTime: new BN(blockTime! + 2),
+        endTime: new BN(blockTime! + duration),
+      })
+      await expectTX(createIncentiveTx, "create incentive").to.be.fulfilled
+
+      const addRewardTx = await wrapper.addReward(rewardAmount)
+      await expectTX(addRewardTx, "add reward").to.be.fulfilled
+
+      multiIncentiveWrappers.push(wrapper)
+    }
+  })
+
+  it('deposit and stake NFT in several incentives', async () => {
+    await sleep(2000) // wait till the incentives start
+
+    const {
+      deposit: _depositWrapper,
+      stakes,
+      tx: depositAndStakeTx
+    } = await cykuraStakerSdk.depositAndStakeMulti(
+      ammAccounts.nftAccount,
+      multiIncentiveWrappers.map((wrapper) => wrapper.incentiveKey)
+    )
+    depositWrapper = _depositWrapper
+    multiStakeWrappers = stakes
+    await expectTX(depositAndStakeTx, "deposit and stake in several incentives").to.be.fulfilled
+
+    const { numberOfStakes } = await depositWrapper.reload()
+    assert.equal(numberOfStakes, 2)
+    for (const [i, stake] of multiStakeWrappers.entries()) {
+      const { incentive } = await stake.reload()
+      assert(incentive.equals(multiIncentiveWrappers[i].incentiveKey))
+    }
+  })
+
+  it('create the reward accounts of the stakes', async () => {
+    const { stakeAccounts, tx: createRewardAccountsTx } = await depositWrapper.stakeAccounts()
+    multiStakeAccounts = stakeAccounts
+    await expectTX(createRewardAccountsTx, "create reward accounts").to.be.fulfilled
+
+    // a stake, its incentive and the reward account of the only reward token
+    assert.equal(multiStakeAccounts.length, 2)
+    multiStakeAccounts.forEach((accounts) => assert.equal(accounts.length, 3))
+  })
+
+  it('reject invalid stake accounts', async () => {
+    const [stakeA, stakeB] = multiStakeAccounts
+
+    // every incentive can be passed once
+    await expectProgramError(
+      await depositWrapper.unstakeAll([stakeA, stakeA]),
+      "InvalidStakeAccount"
+    )
+
+    // every stake of the deposit must be passed
+    await expectProgramError(
+      await depositWrapper.unstakeAll([stakeA]),
+      "MissingStakeAccount"
+    )
+
+    // the reward account must belong to the incentive of the stake
+    await expectProgramError(
+      await depositWrapper.unstakeAll([[stakeA[0], stakeA[1], stakeB[2]], stakeB]),
+      "InvalidRewardAccount"
+    )
+  })
+
+  it('collect the fees of the staked position', async () => {
+    await swapExactInput(provider, ammAccounts.poolState)
+
+    const { amount: balance0Before } = await getTokenAccount(provider, ata0)
+    const { amount: balance1Before } = await getTokenAccount(provider, ata1)
+
+    const u64Max = new BN(1).shln(63)
+    const collectFeesTx = await depositWrapper.collectFees(u64Max, u64Max)
+    await expectTX(collectFeesTx, "collect fees").to.be.fulfilled
+
+    const { amount: balance0After } = await getTokenAccount(provider, ata0)
+    const { amount: balance1After } = await getTokenAccount(provider, ata1)
+    assert(balance0After.add(balance1After).gt(balance0Before.add(balance1Before)))
+
+    // the deposit stays staked
+    const { numberOfStakes } = await depositWrapper.reload()
+    assert.equal(numberOfStakes, 2)
+  })
+
+  it('add and remove liquidity of the staked position', async () => {
+    const slot = await provider.connection.getSlot()
+    const blockTime = await provider.connection.getBlockTime(slot)
+    const deadline = new BN(blockTime! + 100)
+    const { liquidity: liquidityBefore } = await multiStakeWrappers[0].reload()
+
+    const increaseLiquidityTx = await depositWrapper.increaseLiquidity({
+      amount0Desired: new BN(1_000),
+      amount1Desired: new BN(1_000),
+      amount0Minimum: new BN(0),
+      amount1Minimum: new BN(0),
+      deadline,
+    })
+    await expectTX(increaseLiquidityTx, "increase liquidity").to.be.fulfilled
+
+    // every stake is settled at the new liquidity
+    const liquidityAdded = (await multiStakeWrappers[0].reload()).liquidity.sub(liquidityBefore)
+    assert(liquidityAdded.gtn(0))
+    for (const stake of multiStakeWrappers) {
+      const { liquidity } = await stake.reload()
+      assert(liquidity.eq(liquidityBefore.add(liquidityAdded)))
+    }
+
+    const decreaseLiquidityTx = await depositWrapper.decreaseLiquidity({
+      liquidity: liquidityAdded,
+      amount0Minimum: new BN(0),
+      amount1Minimum: new BN(0),
+      deadline,
+    })
+    await expectTX(decreaseLiquidityTx, "decrease liquidity").to.be.fulfilled
+
+    for (const stake of multiStakeWrappers) {
+      const { liquidity } = await stake.reload()
+      assert(liquidity.eq(liquidityBefore))
+    }
+  })
+
+  it('unstake from every incentive and claim the rewards', async () => {
+    const { rewards } = await depositWrapper.stakeAccounts()
+
+    const unstakeAllTx = await depositWrapper.unstakeAll()
+    await expectTX(unstakeAllTx, "unstake from every incentive").to.be.fulfilled
+
+    const { numberOfStakes } = await depositWrapper.reload()
+    assert.equal(numberOfStakes, 0)
+
+    const claimRewardsTx = await cykuraStakerSdk.claimRewardsMulti(
+      rewards.map(({ reward }) => reward)
+    )
+    await expectTX(claimRewardsTx, "claim rewards").to.be.fulfilled
+
+    for (const { reward } of rewards) {
+      const { rewardsOwed } = await new RewardWrapper(cykuraStakerSdk, reward).reload()
+      assert(rewardsOwed.eqn(0))
+    }
+  })
+
+  it('pause staking without blocking claims', async () => {
+    const [config] = await findConfigAddress()
+    const { mint } = await depositWrapper.data()
+    const incentives = multiIncentiveWrappers.map((wrapper) => wrapper.incentiveKey)
+    const rewards = await Promise.all(incentives.map(async (incentive) => (
+      await findRewardAddress(incentive, token1, owner)
+    )[0]))
+
+    const setIncentivePaused = async (paused: boolean) => new TransactionEnvelope(provider, [
+      await program.methods
+        .setIncentivePaused(paused)
+        .accounts({ incentive: incentives[0], authority: owner })
+        .instruction()
+    ])
+    const setPaused = async (paused: boolean) => new TransactionEnvelope(provider, [
+      await program.methods
+        .setPaused(paused)
+        .accounts({ config, admin: owner })
+        .instruction()
+    ])
+
+    // a paused incentive rejects new stakes, but rewards already credited can be claimed
+    await expectTX(await setIncentivePaused(true), "pause incentive").to.be.fulfilled
+    const { tx: stakeTokenMultiTx } = await cykuraStakerSdk.stakeTokenMulti(mint, incentives)
+    await expectProgramError(stakeTokenMultiTx, "IncentivePaused")
+    const { value } = await (await cykuraStakerSdk.claimRewardsMulti(rewards)).simulate()
+    assert.isNull(value.err)
+    await expectTX(await setIncentivePaused(false), "unpause incentive").to.be.fulfilled
+
+    // the program-level pause also blocks claims
+    await expectTX(await setPaused(true), "pause program").to.be.fulfilled
+    await expectProgramError(
+      await cykuraStakerSdk.claimRewardsMulti(rewards),
+      "ProgramPaused"
+    )
+    await expectTX(await setPaused(false), "unpause program").to.be.fulfilled
+  })
+
+  it('extend an incentive with more reward', async () => {
+    const [config] = await findConfigAddress()
+    const wrapper = multiIncentiveWrappers[1]
+    const [vault] = await findVaultAddress(wrapper.incentiveKey, token1)
+    const { endTime: endTimeBefore, rewards } = await wrapper.reload()
+    const newEndTime = endTimeBefore.addn(10)
+    const extendIncentive = async (reward: BN) => new TransactionEnvelope(provider, [
+      await program.methods
+        .extendIncentive(newEndTime, reward, [])
+        .accounts({
+          incentive: wrapper.incentiveKey,
+          authority: owner,
+          config,
+          vault,
+          payer: owner,
+          payerTokenAccount: ata1,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction()
+    ])
+
+    await expectProgramError(await extendIncentive(new BN(0)), "RewardMustBePositive")
+
+    await expectTX(await extendIncentive(rewardAmount), "extend incentive").to.be.fulfilled
+    const incentiveData = await wrapper.reload()
+    assert(incentiveData.endTime.eq(newEndTime))
+    assert(
+      incentiveData.rewards[0].totalRewardUnclaimed.eq(
+        rewards[0].totalRewardUnclaimed.add(rewardAmount)
+      )
+    )
+  })
+
+  it('stake in several incentives and exit', async () => {
+    const { mint } = await depositWrapper.data()
+    const { tx: stakeTokenMultiTx } = await cykuraStakerSdk.stakeTokenMulti(
+      mint,
+      multiIncentiveWrappers.map((wrapper) => wrapper.incentiveKey)
+    )
+    await expectTX(stakeTokenMultiTx, "stake in several incentives").to.be.fulfilled
+
+    const exitTx = await depositWrapper.exit()
+    await expectTX(exitTx, "exit").to.be.fulfilled
+
+    // the deposit is closed and the NFT is back in the wallet
+    assert.isNull(await provider.getAccountInfo(depositWrapper.depositKey))
+    const { amount } = await getTokenAccount(provider, ammAccounts.nftAccount)
+    assert(amount.eqn(1))
+  })
+
+  it('add a second reward token to an incentive', async () => {
+    const slot = await provider.connection.getSlot()
+    const blockTime = await provider.connection.getBlockTime(slot)
+
+    const { wrapper, tx: createIncentiveTx } = await cykuraStakerSdk.createIncentive({
+      rewardToken: token1,
+      pool: ammAccounts.poolState,
+      startTime: new BN(blockTime! + 100),
+      endTime: new BN(blockTime! + 200),
+    })
+    pendingIncentiveWrapper = wrapper
+    await expectTX(createIncentiveTx, "create incentive").to.be.fulfilled
+    await expectTX(await wrapper.addReward(rewardAmount), "add reward").to.be.fulfilled
+
+    const [allowedRewardToken] = await findAllowedMintAddress(token0)
+    const [vault] = await findVaultAddress(wrapper.incentiveKey, token0)
+    const [stakeManager] = await findStakeManagerAddress()
+    const addRewardTokenTx = new TransactionEnvelope(provider, [
+      await program.methods
+        .addRewardToken()
+        .accounts({
+          incentive: wrapper.incentiveKey,
+          rewardToken: token0,
+          allowedRewardToken,
+          vault,
+          stakeManager,
+          refundee: owner,
+          payer: owner,
+          systemProgram: web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: web3.SYSVAR_RENT_PUBKEY,
+        })
+        .instruction()
+    ])
+    await expectTX(addRewardTokenTx, "add reward token").to.be.fulfilled
+
+    const addRewardTx = await wrapper.addReward(rewardAmount.divn(2), token0)
+    await expectTX(addRewardTx, "add reward in the second token").to.be.fulfilled
+
+    const { rewards } = await wrapper.reload()
+    assert(rewards[0].mint.equals(token1))
+    assert(rewards[0].totalRewardUnclaimed.eq(rewardAmount))
+    assert(rewards[1].mint.equals(token0))
+    assert(rewards[1].totalRewardUnclaimed.eq(rewardAmount.divn(2)))
+    const { amount } = await getTokenAccount(provider, vault)
+    assert(amount.eq(rewardAmount.divn(2)))
+  })
+
+  it('cancel an incentive before it starts', async () => {
+    const incentive = pendingIncentiveWrapper.incentiveKey
+    const { rewards, payer } = await pendingIncentiveWrapper.reload()
+    const [vault1] = await findVaultAddress(incentive, token1)
+    const [vault0] = await findVaultAddress(incentive, token0)
+    const [stakeManager] = await findStakeManagerAddress()
+
+    const { amount: balance0Before } = await getTokenAccount(provider, ata0)
+    const { amount: balance1Before } = await getTokenAccount(provider, ata1)
+
+    // the vault and refundee token account of the second reward token are passed as a pair
+    const cancelIncentiveTx = new TransactionEnvelope(provider, [
+      await program.methods
+        .cancelIncentive()
+        .accounts({
+          endIncentive: {
+            incentive,
+            vault: vault1,
+            stakeManager,
+            refundeeTokenAccount: ata1,
+            payer,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          },
+          authority: owner,
+        })
+        .remainingAccounts([
+          { pubkey: vault0, isSigner: false, isWritable: true },
+          { pubkey: ata0, isSigner: false, isWritable: true },
+        ])
+        .instruction()
+    ])
+    await expectTX(cancelIncentiveTx, "cancel incentive").to.be.fulfilled
+
+    // every reward token is refunded and the incentive is closed
+    assert.isNull(await provider.getAccountInfo(incentive))
+    const { amount: balance0After } = await getTokenAccount(provider, ata0)
+    const { amount: balance1After } = await getTokenAccount(provider, ata1)
+    assert(balance1After.sub(balance1Before).eq(rewards[0].totalRewardUnclaimed))
+    assert(balance0After.sub(balance0Before).eq(rewards[1].totalRewardUnclaimed))
+  })
+
   it('end the incentive and reclaim leftover reward', async () => {
     console.log('waiting for incentive to end')
     await sleep(11000)
+    const { rewards } = await incentiveWrapper.reload()
+    const [vault] = await findVaultAddress(incentiveWrapper.incentiveKey, token0)
+    const { amount: balanceBefore } = await getTokenAccount(provider, ata0)
+
     const endIncentiveTx = await incentiveWrapper.endIncentive()
     await expectTX(endIncentiveTx, "end incentive").to.be.fulfilled
+
+    // the leftover reward goes to the refundee, and the emptied vault is closed with the incentive
+    const { amount: balanceAfter } = await getTokenAccount(provider, ata0)
+    assert(
+      balanceAfter.sub(balanceBefore).eq(
+        rewards[0].totalRewardUnclaimed.add(rewards[0].lockBonusReserve)
+      )
+    )
+    assert.isNull(await provider.getAccountInfo(incentiveWrapper.incentiveKey))
+    assert.isNull(await provider.getAccountInfo(vault))
   })
 })